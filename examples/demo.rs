@@ -1,7 +1,10 @@
 use dioxus::prelude::*;
 use dioxus::web;
 use dioxus::web::launch::launch_cfg;
-use dioxus_toastr::{use_toast, ToastKind, ToastOptions, ToastProvider, ToastRequest};
+use dioxus_toastr::{
+    use_toast, ConfirmOptions, CustomKind, StackMode, ToastHistory, ToastKind, ToastOptions,
+    ToastProvider, ToastRequest,
+};
 use std::time::Duration;
 
 const DEMO_CSS: &str = include_str!("demo.css");
@@ -70,29 +73,35 @@ fn App() -> Element {
     let mut rtl = use_signal(|| false);
     let mut prevent_duplicates = use_signal(|| false);
     let mut newest_on_top = use_signal(|| true);
+    let mut stacked = use_signal(|| false);
     let mut tap_to_dismiss = use_signal(|| true);
     let mut time_out = use_signal(|| 5000_u64);
     let mut extended_time_out = use_signal(|| 1000_u64);
     let last_toast_id = use_signal(|| None::<u64>);
     let message_index = use_signal(|| 0_usize);
 
-    let options = {
-        let mut options = ToastOptions::default();
-        options.close_button = *close_button.read();
-        options.progress_bar = *progress_bar.read();
-        options.rtl = *rtl.read();
-        options.prevent_duplicates = *prevent_duplicates.read();
-        options.newest_on_top = *newest_on_top.read();
-        options.tap_to_dismiss = *tap_to_dismiss.read();
-        options.position_class = position.read().class_name();
-        options.time_out = Duration::from_millis(*time_out.read());
-        options.extended_time_out = Duration::from_millis(*extended_time_out.read());
-        options
+    let options = ToastOptions {
+        close_button: *close_button.read(),
+        progress_bar: *progress_bar.read(),
+        rtl: *rtl.read(),
+        prevent_duplicates: *prevent_duplicates.read(),
+        newest_on_top: *newest_on_top.read(),
+        stack_mode: if *stacked.read() {
+            StackMode::Stacked
+        } else {
+            StackMode::List
+        },
+        tap_to_dismiss: *tap_to_dismiss.read(),
+        position_class: position.read().class_name().into(),
+        time_out: Duration::from_millis(*time_out.read()),
+        extended_time_out: Duration::from_millis(*extended_time_out.read()),
+        custom_kinds: vec![CustomKind::new("neutral").with_colors("#E8E8E8", "#333333")],
+        ..ToastOptions::default()
     };
 
     let options_preview = format!(
         "toast[\"{:?}\"](\"{}\", \"{}\")\n\nToastOptions {{\n  close_button: {},\n  progress_bar: {},\n  rtl: {},\n  prevent_duplicates: {},\n  newest_on_top: {},\n  tap_to_dismiss: {},\n  position_class: \"{}\",\n  time_out: {}ms,\n  extended_time_out: {}ms,\n}}",
-        *toast_kind.read(),
+        toast_kind.read(),
         message.read(),
         title.read(),
         close_button.read(),
@@ -190,6 +199,14 @@ fn App() -> Element {
                                 }
                                 "Newest on Top"
                             }
+                            label {
+                                input {
+                                    r#type: "checkbox",
+                                    checked: *stacked.read(),
+                                    oninput: move |evt| stacked.set(evt.checked()),
+                                }
+                                "Stacked"
+                            }
                         }
                     }
 
@@ -232,6 +249,15 @@ fn App() -> Element {
                                 }
                                 "Error"
                             }
+                            label {
+                                input {
+                                    r#type: "radio",
+                                    name: "toast-kind",
+                                    checked: *toast_kind.read() == ToastKind::custom("neutral"),
+                                    onclick: move |_| toast_kind.set(ToastKind::custom("neutral")),
+                                }
+                                "Neutral (custom)"
+                            }
                         }
                     }
 
@@ -300,6 +326,8 @@ fn App() -> Element {
 
                 pre { class: "options-preview", "{options_preview}" }
 
+                ToastHistory {}
+
                 footer { class: "links",
                     h2 { "Links" }
                     ul {
@@ -327,6 +355,7 @@ fn Controls(mut props: ControlsProps) -> Element {
     let toast_for_show = toast.clone();
     let toast_for_clear = toast.clone();
     let toast_for_remove = toast.clone();
+    let toast_for_confirm = toast.clone();
 
     rsx! {
         div { class: "controls",
@@ -345,7 +374,7 @@ fn Controls(mut props: ControlsProps) -> Element {
                         message.to_string()
                     };
 
-                    let mut request = ToastRequest::new(*props.toast_kind.read(), message);
+                    let mut request = ToastRequest::new(props.toast_kind.read().clone(), message);
                     let title = props.title.read();
                     if !title.trim().is_empty() {
                         request = request.with_title(title.to_string());
@@ -377,6 +406,23 @@ fn Controls(mut props: ControlsProps) -> Element {
                 },
                 "Clear Last Toast"
             }
+            button {
+                class: "secondary",
+                onclick: move |_| {
+                    let toast = toast_for_confirm.clone();
+                    spawn(async move {
+                        let confirmed = toast
+                            .confirm("Discard all unsaved changes?", ConfirmOptions::default())
+                            .await;
+                        if confirmed {
+                            toast.success("Changes discarded.");
+                        } else {
+                            toast.info("Nothing was discarded.");
+                        }
+                    });
+                },
+                "Confirm Toast"
+            }
         }
     }
 }
@@ -1,7 +1,7 @@
 use dioxus::prelude::*;
-use dioxus_toastr::{use_toast, ToastProvider};
 use dioxus::web;
 use dioxus::web::launch::launch_cfg;
+use dioxus_toastr::{use_toast, ToastProvider};
 
 fn main() {
     let cfg = web::Config::new().rootname("app-root");
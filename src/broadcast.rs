@@ -0,0 +1,72 @@
+use crate::store::ToastStore;
+use crate::types::{ToastKind, ToastRequest};
+use dioxus::document::Eval;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+const CHANNEL_JS: &str = r#"
+    const name = await dioxus.recv();
+    window.__dioxusToastrOrigin ??= Math.random().toString(36).slice(2);
+    const origin = window.__dioxusToastrOrigin;
+    const channel = new BroadcastChannel(name);
+    channel.onmessage = (event) => {
+        if (event.data && event.data.origin !== origin) {
+            dioxus.send(event.data.toast);
+        }
+    };
+    while (true) {
+        const toast = await dioxus.recv();
+        if (toast === null) break;
+        channel.postMessage({ origin, toast });
+    }
+    channel.close();
+"#;
+
+/// Wire format of a broadcast toast: kind, message, title and timeout in ms.
+type BroadcastToast = (String, String, Option<String>, Option<u64>);
+
+/// Joins the named `BroadcastChannel` so toasts pushed with
+/// `ToastRequest::broadcast()` show up in every tab of the same origin.
+pub(crate) fn use_broadcast(store: ToastStore, name: Option<String>) {
+    use_effect(use_reactive!(|name| {
+        leave(store);
+        let Some(name) = name else {
+            return;
+        };
+        let mut eval = document::eval(CHANNEL_JS);
+        if eval.send(name).is_err() {
+            return;
+        }
+        store.set_broadcast(Some(eval));
+        spawn(async move {
+            while let Ok(toast) = eval.recv::<BroadcastToast>().await {
+                store.push(from_wire(toast));
+            }
+        });
+    }));
+
+    use_drop(move || leave(store));
+}
+
+fn leave(store: ToastStore) {
+    if let Some(eval) = store.set_broadcast(None) {
+        let _ = eval.send(None::<BroadcastToast>);
+    }
+}
+
+pub(crate) fn post(eval: Eval, request: &ToastRequest) {
+    let toast: BroadcastToast = (
+        request.kind.to_string(),
+        request.message.clone(),
+        request.title.clone(),
+        request.time_out.map(|time_out| time_out.as_millis() as u64),
+    );
+    let _ = eval.send(Some(toast));
+}
+
+fn from_wire((kind, message, title, time_out): BroadcastToast) -> ToastRequest {
+    let mut request = ToastRequest::new(ToastKind::from(kind), message);
+    request.title = title;
+    request.time_out = time_out.map(Duration::from_millis);
+    request
+}
@@ -0,0 +1,210 @@
+use crate::time::{now_ms, sleep};
+use futures_channel::oneshot;
+use futures_util::future::LocalBoxFuture;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Time source for every toast timeout, extended timeout and animation tick.
+pub trait ToastClock {
+    /// Milliseconds since the Unix epoch.
+    fn now(&self) -> u64;
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()>;
+}
+
+/// The wall clock, backed by `gloo-timers` on the web and tokio or
+/// `futures-timer` elsewhere.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl ToastClock for SystemClock {
+    fn now(&self) -> u64 {
+        now_ms()
+    }
+
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+        Box::pin(sleep(duration))
+    }
+}
+
+/// A clock that only moves when told to, for deterministic tests. Clones share
+/// the same time.
+#[derive(Clone, Debug, Default)]
+pub struct MockClock {
+    state: Rc<RefCell<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    now: u64,
+    sleepers: Vec<(u64, oneshot::Sender<()>)>,
+}
+
+impl MockClock {
+    pub fn new(now: u64) -> Self {
+        let clock = Self::default();
+        clock.state.borrow_mut().now = now;
+        clock
+    }
+
+    pub fn now(&self) -> u64 {
+        self.state.borrow().now
+    }
+
+    /// Moves time forward and wakes every sleep that has run out.
+    pub fn advance(&self, duration: Duration) {
+        let now = self.now() + duration.as_millis() as u64;
+        self.set(now);
+    }
+
+    /// How many `sleep` futures are still alive and waiting for their
+    /// deadline.
+    pub fn pending_sleeps(&self) -> usize {
+        self.state
+            .borrow()
+            .sleepers
+            .iter()
+            .filter(|(_, sender)| !sender.is_canceled())
+            .count()
+    }
+
+    pub fn set(&self, now: u64) {
+        let due: Vec<_> = {
+            let mut state = self.state.borrow_mut();
+            state.now = now;
+            let (due, pending) = std::mem::take(&mut state.sleepers)
+                .into_iter()
+                .partition(|(deadline, _)| *deadline <= now);
+            state.sleepers = pending;
+            due
+        };
+        for (_, sender) in due {
+            let _ = sender.send(());
+        }
+    }
+}
+
+impl PartialEq for MockClock {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl ToastClock for MockClock {
+    fn now(&self) -> u64 {
+        MockClock::now(self)
+    }
+
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+        let (sender, receiver) = oneshot::channel();
+        let mut state = self.state.borrow_mut();
+        let deadline = state.now + duration.as_millis() as u64;
+        if deadline <= state.now {
+            let _ = sender.send(());
+        } else {
+            state.sleepers.push((deadline, sender));
+        }
+        Box::pin(async move {
+            let _ = receiver.await;
+        })
+    }
+}
+
+/// Shared handle to a [`ToastClock`], passed to `ToastProvider { clock }`.
+#[derive(Clone)]
+pub struct ToastClockHandle(Rc<dyn ToastClock>);
+
+impl ToastClockHandle {
+    pub fn new(clock: impl ToastClock + 'static) -> Self {
+        Self(Rc::new(clock))
+    }
+
+    pub fn now(&self) -> u64 {
+        self.0.now()
+    }
+
+    pub fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+        self.0.sleep(duration)
+    }
+}
+
+impl Default for ToastClockHandle {
+    fn default() -> Self {
+        Self::new(SystemClock)
+    }
+}
+
+impl PartialEq for ToastClockHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for ToastClockHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToastClockHandle").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+
+    #[test]
+    fn set_wakes_only_the_sleeps_that_are_due() {
+        let clock = MockClock::new(1_000);
+        let mut short = ToastClock::sleep(&clock, Duration::from_millis(100));
+        let mut exact = ToastClock::sleep(&clock, Duration::from_millis(500));
+        let mut long = ToastClock::sleep(&clock, Duration::from_millis(900));
+
+        clock.set(1_500);
+        assert_eq!(clock.now(), 1_500);
+        assert!((&mut short).now_or_never().is_some());
+        assert!((&mut exact).now_or_never().is_some());
+        assert!((&mut long).now_or_never().is_none());
+        assert_eq!(clock.state.borrow().sleepers.len(), 1);
+
+        clock.set(1_900);
+        assert!((&mut long).now_or_never().is_some());
+        assert!(clock.state.borrow().sleepers.is_empty());
+    }
+
+    #[test]
+    fn advance_fires_sleeps_in_deadline_order() {
+        let clock = MockClock::new(0);
+        let mut sleeps: Vec<_> = [300, 100, 200]
+            .into_iter()
+            .map(|ms| ToastClock::sleep(&clock, Duration::from_millis(ms)))
+            .collect();
+
+        let mut fired = Vec::new();
+        for _ in 0..3 {
+            clock.advance(Duration::from_millis(100));
+            for (index, sleep) in sleeps.iter_mut().enumerate() {
+                if !fired.contains(&index) && sleep.now_or_never().is_some() {
+                    fired.push(index);
+                }
+            }
+        }
+        assert_eq!(fired, [1, 2, 0]);
+    }
+
+    #[test]
+    fn zero_sleep_is_ready_immediately() {
+        let clock = MockClock::new(42);
+        assert!(ToastClock::sleep(&clock, Duration::ZERO)
+            .now_or_never()
+            .is_some());
+        assert!(clock.state.borrow().sleepers.is_empty());
+    }
+
+    #[test]
+    fn clones_share_time() {
+        let clock = MockClock::new(0);
+        let handle = ToastClockHandle::new(clock.clone());
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(handle.now(), 2_000);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::handle::ToastHandle;
+use crate::headless::CoreRequest;
+use crate::store::ToastStore;
+use crate::types::{Toast, ToastRequest};
+use dioxus::core::Runtime;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A server function response that carries toasts along with its value.
+///
+/// Deserializing it has no side effects; the client shows the toasts when it
+/// unwraps the value with [`WithToasts::into_inner`], which pushes them
+/// through the nearest `ToastProvider`. Toasts travel as plain
+/// [`CoreRequest`]s, so the response stays `Send`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WithToasts<T> {
+    pub value: T,
+    #[serde(default)]
+    pub toasts: Vec<CoreRequest>,
+}
+
+impl<T> WithToasts<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            toasts: Vec::new(),
+        }
+    }
+
+    pub fn with_toast(mut self, request: impl Into<CoreRequest>) -> Self {
+        self.toasts.push(request.into());
+        self
+    }
+
+    /// Pushes the toasts through the `ToastProvider` around the calling
+    /// component and returns the value. Without a provider, e.g. when called
+    /// on the server, the toasts are dropped.
+    pub fn into_inner(self) -> T {
+        if Runtime::try_current().is_some() {
+            if let Some(store) = try_consume_context::<ToastStore>() {
+                for request in self.toasts {
+                    store.push(request.into());
+                }
+            }
+        }
+        self.value
+    }
+
+    /// Pushes the toasts through `toast`, e.g. a scoped provider from
+    /// `use_toast_in`, and returns the value.
+    pub fn into_inner_with(self, toast: &ToastHandle) -> T {
+        for request in self.toasts {
+            toast.push(request.into());
+        }
+        self.value
+    }
+}
+
+impl<T> From<T> for WithToasts<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Carries the toasts pushed while the server rendered the page over to the
+/// client. Rendered after the provider's children, so their pushes are
+/// included; the client then restores them with the server's ids and starts
+/// their timers.
+#[component]
+pub(crate) fn ToastHydration(store: ToastStore) -> Element {
+    let toasts = use_server_cached(move || {
+        store
+            .peek_toasts()
+            .iter()
+            .map(|toast| (toast.id, server_request(toast)))
+            .collect::<Vec<_>>()
+    });
+    use_hook(move || store.hydrate(toasts));
+    rsx! {}
+}
+
+fn server_request(toast: &Toast) -> ToastRequest {
+    let mut request =
+        ToastRequest::new(toast.kind.clone(), toast.message.clone()).with_timeout(toast.time_out);
+    request.title = toast.title.clone();
+    request.persist = toast.persist;
+    request.confirm = toast.confirm.clone();
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ToastKind, ToastOptions};
+
+    fn app() -> Element {
+        rsx! {}
+    }
+
+    #[test]
+    fn into_inner_pushes_through_the_provider_in_scope() {
+        let response = WithToasts::new(42).with_toast(ToastRequest::new(
+            ToastKind::Warning,
+            "Quota almost reached",
+        ));
+        assert_eq!(response.clone().into_inner(), 42);
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dom.in_scope(ScopeId::APP, || {
+            let store = provide_context(ToastStore::new(ToastOptions::default()));
+            assert_eq!(response.into_inner(), 42);
+            let toasts = store.peek_toasts();
+            assert_eq!(toasts.len(), 1);
+            assert_eq!(toasts[0].message, "Quota almost reached");
+        });
+    }
+}
@@ -0,0 +1,178 @@
+const DISMISS_DISTANCE: f64 = 80.0;
+const DISMISS_VELOCITY: f64 = 0.5;
+const CLICK_SLOP: f64 = 5.0;
+
+/// Captures the pointer on `pointerdown` inside a swipeable toast, so a fast
+/// swipe keeps reporting moves after it leaves the element. Mouse pointers
+/// never swipe, and buttons are left alone because capture would retarget
+/// their clicks to the toast.
+pub(crate) const POINTER_CAPTURE_JS: &str = r#"
+    if (!window.__dioxusToastrCapture) {
+        window.__dioxusToastrCapture = true;
+        document.addEventListener("pointerdown", (event) => {
+            if (event.pointerType === "mouse" || event.target.closest("button")) return;
+            const toast = event.target.closest("[data-toast-swipe]");
+            if (toast) toast.setPointerCapture(event.pointerId);
+        }, true);
+    }
+"#;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SwipeAxis {
+    Horizontal,
+    Vertical,
+}
+
+impl SwipeAxis {
+    pub(crate) fn for_position(position_class: &str) -> Self {
+        if position_class.contains("full-width") || position_class.contains("center") {
+            SwipeAxis::Vertical
+        } else {
+            SwipeAxis::Horizontal
+        }
+    }
+
+    pub(crate) fn touch_action(self) -> &'static str {
+        match self {
+            SwipeAxis::Horizontal => "pan-y",
+            SwipeAxis::Vertical => "pan-x",
+        }
+    }
+}
+
+/// Tracks a single pointer drag on a toast.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Swipe {
+    axis: SwipeAxis,
+    pointer_id: i32,
+    start: f64,
+    last: f64,
+    last_at: u64,
+    offset: f64,
+    velocity: f64,
+    moved: bool,
+}
+
+impl Swipe {
+    pub(crate) fn start(axis: SwipeAxis, pointer_id: i32, x: f64, y: f64, now: u64) -> Self {
+        let position = Self::along(axis, x, y);
+        Self {
+            axis,
+            pointer_id,
+            start: position,
+            last: position,
+            last_at: now,
+            offset: 0.0,
+            velocity: 0.0,
+            moved: false,
+        }
+    }
+
+    pub(crate) fn pointer_id(&self) -> i32 {
+        self.pointer_id
+    }
+
+    pub(crate) fn moved(&self) -> bool {
+        self.moved
+    }
+
+    pub(crate) fn update(&mut self, x: f64, y: f64, now: u64) {
+        let position = Self::along(self.axis, x, y);
+        let elapsed = now.saturating_sub(self.last_at);
+        if elapsed > 0 {
+            self.velocity = (position - self.last) / elapsed as f64;
+            self.last_at = now;
+        }
+        self.last = position;
+        self.offset = position - self.start;
+        self.moved |= self.offset.abs() > CLICK_SLOP;
+    }
+
+    pub(crate) fn should_dismiss(&self) -> bool {
+        self.offset.abs() > DISMISS_DISTANCE
+            || (self.velocity.abs() > DISMISS_VELOCITY && self.velocity * self.offset > 0.0)
+    }
+
+    pub(crate) fn translate(&self) -> String {
+        match self.axis {
+            SwipeAxis::Horizontal => format!("translate: {}px 0;", self.offset),
+            SwipeAxis::Vertical => format!("translate: 0 {}px;", self.offset),
+        }
+    }
+
+    fn along(axis: SwipeAxis, x: f64, y: f64) -> f64 {
+        match axis {
+            SwipeAxis::Horizontal => x,
+            SwipeAxis::Vertical => y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drag(points: &[(f64, u64)]) -> Swipe {
+        let (start, at) = points[0];
+        let mut swipe = Swipe::start(SwipeAxis::Horizontal, 1, start, 0.0, at);
+        for &(x, at) in &points[1..] {
+            swipe.update(x, 0.0, at);
+        }
+        swipe
+    }
+
+    #[test]
+    fn axis_follows_the_position() {
+        assert_eq!(
+            SwipeAxis::for_position("toast-top-right"),
+            SwipeAxis::Horizontal
+        );
+        assert_eq!(
+            SwipeAxis::for_position("toast-top-center"),
+            SwipeAxis::Vertical
+        );
+        assert_eq!(
+            SwipeAxis::for_position("toast-bottom-full-width"),
+            SwipeAxis::Vertical
+        );
+    }
+
+    #[test]
+    fn slow_drag_dismisses_only_past_the_distance() {
+        let short = drag(&[(0.0, 0), (40.0, 1_000), (80.0, 2_000)]);
+        assert!(!short.should_dismiss());
+        let far = drag(&[(0.0, 0), (40.0, 1_000), (81.0, 2_000)]);
+        assert!(far.should_dismiss());
+        let left = drag(&[(0.0, 0), (-81.0, 2_000)]);
+        assert!(left.should_dismiss());
+    }
+
+    #[test]
+    fn fast_flick_dismisses_in_the_direction_of_travel() {
+        let flick = drag(&[(0.0, 0), (30.0, 40)]);
+        assert!(flick.should_dismiss());
+        let pulled_back = drag(&[(0.0, 0), (60.0, 1_000), (30.0, 1_040)]);
+        assert!(!pulled_back.should_dismiss());
+    }
+
+    #[test]
+    fn velocity_ignores_moves_without_elapsed_time() {
+        let swipe = drag(&[(0.0, 0), (10.0, 100), (50.0, 100)]);
+        assert!(!swipe.should_dismiss());
+        assert_eq!(swipe.translate(), "translate: 50px 0;");
+    }
+
+    #[test]
+    fn moves_within_the_slop_still_count_as_a_click() {
+        assert!(!drag(&[(0.0, 0), (5.0, 100)]).moved());
+        assert!(drag(&[(0.0, 0), (6.0, 100)]).moved());
+    }
+
+    #[test]
+    fn vertical_axis_reads_y() {
+        let mut swipe = Swipe::start(SwipeAxis::Vertical, 1, 0.0, 0.0, 0);
+        swipe.update(500.0, 90.0, 1_000);
+        assert!(swipe.should_dismiss());
+        assert_eq!(swipe.translate(), "translate: 0 90px;");
+    }
+}
@@ -1,6 +1,10 @@
+use crate::registry::use_toast_registry;
 use crate::store::ToastStore;
-use crate::types::{ToastId, ToastKind, ToastRequest};
+use crate::types::{ConfirmOptions, ToastId, ToastKind, ToastRequest};
 use dioxus::prelude::use_context;
+use futures_channel::oneshot;
+use std::future::Future;
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct ToastHandle {
@@ -13,7 +17,8 @@ impl ToastHandle {
     }
 
     pub fn success(&self, message: impl Into<String>) -> ToastId {
-        self.store.push(ToastRequest::new(ToastKind::Success, message))
+        self.store
+            .push(ToastRequest::new(ToastKind::Success, message))
     }
 
     pub fn info(&self, message: impl Into<String>) -> ToastId {
@@ -21,11 +26,60 @@ impl ToastHandle {
     }
 
     pub fn warning(&self, message: impl Into<String>) -> ToastId {
-        self.store.push(ToastRequest::new(ToastKind::Warning, message))
+        self.store
+            .push(ToastRequest::new(ToastKind::Warning, message))
     }
 
     pub fn error(&self, message: impl Into<String>) -> ToastId {
-        self.store.push(ToastRequest::new(ToastKind::Error, message))
+        self.store
+            .push(ToastRequest::new(ToastKind::Error, message))
+    }
+
+    /// Shows a sticky toast with a spinner; drive it with `set_progress` and
+    /// remove it (or `set_kind` it into another kind) when the work is done.
+    pub fn loading(&self, message: impl Into<String>) -> ToastId {
+        self.store
+            .push(ToastRequest::new(ToastKind::Loading, message).with_timeout(Duration::ZERO))
+    }
+
+    pub fn set_progress(&self, id: ToastId, progress: f32) -> bool {
+        self.store.set_progress(id, progress)
+    }
+
+    /// Turns a visible toast into another kind. Pair it with `set_timeout`
+    /// when the new kind should close on its own.
+    pub fn set_kind(&self, id: ToastId, kind: ToastKind) -> bool {
+        self.store.set_kind(id, kind)
+    }
+
+    pub fn set_message(&self, id: ToastId, message: impl Into<String>) -> bool {
+        self.store.set_message(id, message)
+    }
+
+    pub fn set_title(&self, id: ToastId, title: Option<String>) -> bool {
+        self.store.set_title(id, title)
+    }
+
+    /// Restarts the toast's timer with a new timeout; `Duration::ZERO` makes
+    /// it sticky.
+    pub fn set_timeout(&self, id: ToastId, time_out: Duration) {
+        self.store.set_timeout(id, time_out);
+    }
+
+    /// Shows a toast with Confirm/Cancel buttons. Resolves to `true` only when
+    /// Confirm is clicked; dismissing the toast or letting it time out yields
+    /// `false`.
+    pub fn confirm(
+        &self,
+        message: impl Into<String>,
+        options: ConfirmOptions,
+    ) -> impl Future<Output = bool> + 'static {
+        let (sender, receiver) = oneshot::channel();
+        let id = self.store.push(options.into_request(message.into()));
+        if id != 0 {
+            self.store.register_confirm(id, sender);
+        }
+        async move { receiver.await.unwrap_or(false) }
     }
 
     pub fn remove(&self, id: ToastId) {
@@ -41,3 +95,12 @@ pub fn use_toast() -> ToastHandle {
     let store = use_context::<ToastStore>();
     ToastHandle { store }
 }
+
+/// Returns a handle to the scoped `ToastProvider { name }` with this name. It
+/// works anywhere in the app, even outside that provider's subtree, and is
+/// `None` while no provider with that name is mounted. The caller re-renders
+/// when one mounts or unmounts.
+pub fn use_toast_in(name: &str) -> Option<ToastHandle> {
+    let registry = use_toast_registry();
+    registry.get(name).map(|store| ToastHandle { store })
+}
@@ -0,0 +1,524 @@
+//! Renderer-independent toast lifecycle: ids, ordering, duplicate checks and
+//! pausable timers. Nothing here touches the Dioxus runtime; every method that
+//! depends on time takes `now` in milliseconds since the Unix epoch, so a TUI,
+//! a desktop shell or a test can drive it from any clock.
+//!
+//! The driver calls [`ToastCore::expire`] at or after
+//! [`ToastCore::next_deadline`] to dismiss toasts that ran out of time.
+//!
+//! Everything here is plain data and `Send`; icons and click callbacks stay
+//! in the Dioxus `ToastStore` layer.
+
+use crate::types::{ToastId, ToastKind, ToastOptions, ToastRequest};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The part of a toast request the lifecycle depends on.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoreRequest {
+    pub kind: ToastKind,
+    pub message: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub title: Option<String>,
+    /// Falls back to the kind's timeout; `Duration::ZERO` is sticky.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::types::millis::option")
+    )]
+    pub time_out: Option<Duration>,
+}
+
+impl CoreRequest {
+    pub fn new(kind: ToastKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            title: None,
+            time_out: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.time_out = Some(timeout);
+        self
+    }
+}
+
+impl From<&ToastRequest> for CoreRequest {
+    fn from(request: &ToastRequest) -> Self {
+        Self {
+            kind: request.kind.clone(),
+            message: request.message.clone(),
+            title: request.title.clone(),
+            time_out: request.time_out,
+        }
+    }
+}
+
+impl From<ToastRequest> for CoreRequest {
+    fn from(request: ToastRequest) -> Self {
+        Self::from(&request)
+    }
+}
+
+impl From<CoreRequest> for ToastRequest {
+    fn from(request: CoreRequest) -> Self {
+        let mut toast = ToastRequest::new(request.kind, request.message);
+        toast.title = request.title;
+        toast.time_out = request.time_out;
+        toast
+    }
+}
+
+/// Lifecycle state of one visible toast.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoreToast {
+    pub id: ToastId,
+    pub kind: ToastKind,
+    pub message: String,
+    pub title: Option<String>,
+    /// `Duration::ZERO` for sticky toasts.
+    pub time_out: Duration,
+    pub created_at: u64,
+    /// When the toast was first shown with its timer allowed to run. Later
+    /// than `created_at` for toasts pushed while `pause_all` held them back,
+    /// and `None` until then.
+    pub shown_at: Option<u64>,
+    /// Time left as of `resumed_at`, or right now while paused.
+    pub remaining: Duration,
+    /// When the timer last started running; `None` while paused or sticky.
+    pub resumed_at: Option<u64>,
+    pauses: u32,
+}
+
+impl CoreToast {
+    pub fn is_sticky(&self) -> bool {
+        self.time_out.is_zero()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        !self.is_sticky() && self.resumed_at.is_none()
+    }
+
+    pub fn remaining_at(&self, now: u64) -> Duration {
+        match self.resumed_at {
+            Some(resumed_at) => self
+                .remaining
+                .saturating_sub(Duration::from_millis(now.saturating_sub(resumed_at))),
+            None => self.remaining,
+        }
+    }
+
+    /// When a running timer fires; `None` while paused or sticky.
+    pub fn deadline(&self) -> Option<u64> {
+        self.resumed_at
+            .map(|resumed_at| resumed_at + self.remaining.as_millis() as u64)
+    }
+
+    fn stop(&mut self, now: u64) {
+        if self.resumed_at.is_some() {
+            self.remaining = self.remaining_at(now);
+            self.resumed_at = None;
+        }
+    }
+
+    fn start(&mut self, now: u64) {
+        if self.pauses == 0 && self.shown_at.is_none() {
+            self.shown_at = Some(now);
+        }
+        if !self.is_sticky() && self.pauses == 0 && self.resumed_at.is_none() {
+            self.resumed_at = Some(now);
+        }
+    }
+}
+
+/// The toast queue without any rendering or scheduling attached.
+#[derive(Clone, Debug)]
+pub struct ToastCore {
+    toasts: Vec<CoreToast>,
+    next_id: ToastId,
+    options: ToastOptions,
+    all_paused: bool,
+}
+
+impl ToastCore {
+    pub fn new(options: ToastOptions) -> Self {
+        Self {
+            toasts: Vec::new(),
+            next_id: 1,
+            options,
+            all_paused: false,
+        }
+    }
+
+    pub fn options(&self) -> &ToastOptions {
+        &self.options
+    }
+
+    pub fn set_options(&mut self, options: ToastOptions) {
+        self.options = options;
+    }
+
+    /// Visible toasts in display order.
+    pub fn toasts(&self) -> &[CoreToast] {
+        &self.toasts
+    }
+
+    pub fn get(&self, id: ToastId) -> Option<&CoreToast> {
+        self.toasts.iter().find(|toast| toast.id == id)
+    }
+
+    /// Display index of a visible toast.
+    pub fn position(&self, id: ToastId) -> Option<usize> {
+        self.toasts.iter().position(|toast| toast.id == id)
+    }
+
+    /// Adds a toast and starts its timer. Returns `None` when
+    /// `prevent_duplicates` rejects it.
+    pub fn push(&mut self, request: &CoreRequest, now: u64) -> Option<ToastId> {
+        self.push_with_id(self.next_id, request, now)
+    }
+
+    /// Like [`ToastCore::push`], but under an id assigned elsewhere, e.g. by
+    /// the server that rendered the page. Later pushes continue after it.
+    pub fn push_with_id(
+        &mut self,
+        id: ToastId,
+        request: &CoreRequest,
+        now: u64,
+    ) -> Option<ToastId> {
+        let time_out = request
+            .time_out
+            .unwrap_or_else(|| self.options.resolve_kind(&request.kind).time_out);
+        if self.options.prevent_duplicates
+            && self.toasts.iter().any(|toast| {
+                toast.message == request.message
+                    && toast.title == request.title
+                    && toast.kind == request.kind
+            })
+        {
+            return None;
+        }
+
+        self.next_id = self.next_id.max(id + 1);
+        let mut toast = CoreToast {
+            id,
+            kind: request.kind.clone(),
+            message: request.message.clone(),
+            title: request.title.clone(),
+            time_out,
+            created_at: now,
+            shown_at: None,
+            remaining: time_out,
+            resumed_at: None,
+            pauses: u32::from(self.all_paused),
+        };
+        toast.start(now);
+        if self.options.newest_on_top {
+            self.toasts.insert(0, toast);
+        } else {
+            self.toasts.push(toast);
+        }
+        Some(id)
+    }
+
+    pub fn dismiss(&mut self, id: ToastId) -> Option<CoreToast> {
+        let index = self.position(id)?;
+        Some(self.toasts.remove(index))
+    }
+
+    pub fn clear(&mut self) -> Vec<CoreToast> {
+        std::mem::take(&mut self.toasts)
+    }
+
+    /// Stops a toast's timer. Pauses nest: the timer only runs again once
+    /// every `pause` has been matched by a `resume`.
+    pub fn pause(&mut self, id: ToastId, now: u64) -> bool {
+        let Some(toast) = self.toasts.iter_mut().find(|toast| toast.id == id) else {
+            return false;
+        };
+        toast.pauses += 1;
+        toast.stop(now);
+        true
+    }
+
+    pub fn resume(&mut self, id: ToastId, now: u64) -> bool {
+        let Some(toast) = self.toasts.iter_mut().find(|toast| toast.id == id) else {
+            return false;
+        };
+        toast.pauses = toast.pauses.saturating_sub(1);
+        toast.start(now);
+        true
+    }
+
+    /// Resumes a paused toast with the kind's `extended_time_out` left on its
+    /// timer, as after the pointer leaves a hovered toast. Sticky toasts stay
+    /// sticky.
+    pub fn resume_extended(&mut self, id: ToastId, now: u64) -> bool {
+        let Some(toast) = self.toasts.iter_mut().find(|toast| toast.id == id) else {
+            return false;
+        };
+        let extended = self.options.resolve_kind(&toast.kind).extended_time_out;
+        toast.pauses = toast.pauses.saturating_sub(1);
+        if toast.pauses == 0 && toast.is_paused() && !extended.is_zero() {
+            toast.remaining = extended;
+        }
+        toast.start(now);
+        true
+    }
+
+    /// Pauses every toast, including ones pushed before `resume_all`. Calling
+    /// it again while already paused has no effect.
+    pub fn pause_all(&mut self, now: u64) {
+        if self.all_paused {
+            return;
+        }
+        self.all_paused = true;
+        for toast in &mut self.toasts {
+            toast.pauses += 1;
+            toast.stop(now);
+        }
+    }
+
+    pub fn resume_all(&mut self, now: u64) {
+        if !self.all_paused {
+            return;
+        }
+        self.all_paused = false;
+        for toast in &mut self.toasts {
+            toast.pauses = toast.pauses.saturating_sub(1);
+            toast.start(now);
+        }
+    }
+
+    pub fn set_kind(&mut self, id: ToastId, kind: ToastKind) -> bool {
+        self.edit(id, |toast| toast.kind = kind)
+    }
+
+    pub fn set_message(&mut self, id: ToastId, message: String) -> bool {
+        self.edit(id, |toast| toast.message = message)
+    }
+
+    pub fn set_title(&mut self, id: ToastId, title: Option<String>) -> bool {
+        self.edit(id, |toast| toast.title = title)
+    }
+
+    fn edit(&mut self, id: ToastId, edit: impl FnOnce(&mut CoreToast)) -> bool {
+        match self.toasts.iter_mut().find(|toast| toast.id == id) {
+            Some(toast) => {
+                edit(toast);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Restarts a toast's timer with a new timeout. `Duration::ZERO` makes it
+    /// sticky.
+    pub fn set_timeout(&mut self, id: ToastId, time_out: Duration, now: u64) -> bool {
+        let Some(toast) = self.toasts.iter_mut().find(|toast| toast.id == id) else {
+            return false;
+        };
+        toast.time_out = time_out;
+        toast.remaining = time_out;
+        toast.resumed_at = None;
+        toast.start(now);
+        true
+    }
+
+    /// The earliest time at which a running timer fires.
+    pub fn next_deadline(&self) -> Option<u64> {
+        self.toasts.iter().filter_map(CoreToast::deadline).min()
+    }
+
+    /// Removes and returns every toast whose timer has run out by `now`.
+    pub fn expire(&mut self, now: u64) -> Vec<CoreToast> {
+        let mut expired = Vec::new();
+        self.toasts.retain(|toast| {
+            let due = toast.deadline().is_some_and(|deadline| deadline <= now);
+            if due {
+                expired.push(toast.clone());
+            }
+            !due
+        });
+        expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(message: &str) -> CoreRequest {
+        CoreRequest::new(ToastKind::Info, message)
+    }
+
+    fn core() -> ToastCore {
+        ToastCore::new(ToastOptions::default())
+    }
+
+    #[test]
+    fn plain_data_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CoreRequest>();
+        assert_send::<ToastCore>();
+    }
+
+    #[test]
+    fn push_orders_newest_first_and_assigns_ids() {
+        let mut core = core();
+        let first = core.push(&info("first"), 0).unwrap();
+        let second = core.push(&info("second"), 0).unwrap();
+        assert_eq!((first, second), (1, 2));
+        assert_eq!(core.position(second), Some(0));
+
+        core.set_options(ToastOptions {
+            newest_on_top: false,
+            ..ToastOptions::default()
+        });
+        let third = core.push(&info("third"), 0).unwrap();
+        assert_eq!(core.position(third), Some(2));
+    }
+
+    #[test]
+    fn push_with_id_continues_after_the_assigned_id() {
+        let mut core = core();
+        core.push_with_id(7, &info("server"), 0);
+        assert_eq!(core.push(&info("client"), 0), Some(8));
+    }
+
+    #[test]
+    fn prevent_duplicates_rejects_identical_toasts() {
+        let mut core = ToastCore::new(ToastOptions {
+            prevent_duplicates: true,
+            ..ToastOptions::default()
+        });
+        assert!(core.push(&info("saved"), 0).is_some());
+        assert!(core.push(&info("saved"), 0).is_none());
+        assert!(core.push(&info("saved").with_title("Sync"), 0).is_some());
+    }
+
+    #[test]
+    fn setters_change_what_prevent_duplicates_compares() {
+        let mut core = ToastCore::new(ToastOptions {
+            prevent_duplicates: true,
+            ..ToastOptions::default()
+        });
+        let id = core.push(&info("uploading"), 0).unwrap();
+        assert!(core.set_message(id, "uploaded".to_string()));
+        assert!(core.push(&info("uploading"), 0).is_some());
+        assert!(core.push(&info("uploaded"), 0).is_none());
+        assert!(!core.set_kind(99, ToastKind::Success));
+    }
+
+    #[test]
+    fn next_deadline_and_expire_follow_each_timeout() {
+        let mut core = core();
+        let short = core
+            .push(&info("short").with_timeout(Duration::from_secs(1)), 1_000)
+            .unwrap();
+        let long = core.push(&info("long"), 1_000).unwrap();
+        core.push(&info("sticky").with_timeout(Duration::ZERO), 1_000);
+
+        assert_eq!(core.next_deadline(), Some(2_000));
+        assert!(core.expire(1_999).is_empty());
+        let expired: Vec<_> = core.expire(2_000).iter().map(|toast| toast.id).collect();
+        assert_eq!(expired, [short]);
+        assert_eq!(core.next_deadline(), Some(6_000));
+        assert_eq!(core.expire(10_000)[0].id, long);
+        assert_eq!(core.next_deadline(), None);
+        assert_eq!(core.toasts().len(), 1);
+    }
+
+    #[test]
+    fn nested_pauses_resume_only_after_the_last_resume() {
+        let mut core = core();
+        let id = core.push(&info("saved"), 0).unwrap();
+        core.pause(id, 1_000);
+        core.pause(id, 2_000);
+        assert!(core.get(id).unwrap().is_paused());
+        assert_eq!(core.get(id).unwrap().remaining, Duration::from_secs(4));
+
+        core.resume(id, 3_000);
+        assert!(core.get(id).unwrap().is_paused());
+        assert_eq!(core.next_deadline(), None);
+
+        core.resume(id, 4_000);
+        assert_eq!(core.next_deadline(), Some(8_000));
+        assert_eq!(
+            core.get(id).unwrap().remaining_at(6_000),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn pause_all_covers_toasts_pushed_while_paused() {
+        let mut core = core();
+        let before = core.push(&info("before"), 0).unwrap();
+        core.pause_all(1_000);
+        core.pause_all(1_500);
+        let during = core.push(&info("during"), 2_000).unwrap();
+        assert!(core.get(before).unwrap().is_paused());
+        assert!(core.get(during).unwrap().is_paused());
+        assert_eq!(core.get(before).unwrap().shown_at, Some(0));
+        assert_eq!(core.get(during).unwrap().shown_at, None);
+
+        core.pause(before, 2_500);
+        core.resume_all(3_000);
+        assert!(core.get(before).unwrap().is_paused());
+        assert_eq!(core.get(during).unwrap().deadline(), Some(8_000));
+        assert_eq!(core.get(during).unwrap().shown_at, Some(3_000));
+
+        core.resume(before, 4_000);
+        assert_eq!(core.get(before).unwrap().deadline(), Some(8_000));
+    }
+
+    #[test]
+    fn resume_extended_uses_the_kind_extended_timeout() {
+        let mut options = ToastOptions::default();
+        options.kind_defaults.insert(
+            ToastKind::Error,
+            crate::types::KindOptions {
+                extended_time_out: Some(Duration::from_secs(3)),
+                ..Default::default()
+            },
+        );
+        let mut core = ToastCore::new(options);
+        let info = core.push(&info("info"), 0).unwrap();
+        let error = core
+            .push(&CoreRequest::new(ToastKind::Error, "error"), 0)
+            .unwrap();
+        let sticky = core
+            .push(
+                &CoreRequest::new(ToastKind::Info, "sticky").with_timeout(Duration::ZERO),
+                0,
+            )
+            .unwrap();
+        for id in [info, error, sticky] {
+            core.pause(id, 100);
+            core.resume_extended(id, 200);
+        }
+        assert_eq!(core.get(info).unwrap().deadline(), Some(1_200));
+        assert_eq!(core.get(error).unwrap().deadline(), Some(3_200));
+        assert!(core.get(sticky).unwrap().is_sticky());
+        assert_eq!(core.get(sticky).unwrap().deadline(), None);
+    }
+
+    #[test]
+    fn set_timeout_restarts_the_timer() {
+        let mut core = core();
+        let id = core
+            .push(&info("loading").with_timeout(Duration::ZERO), 0)
+            .unwrap();
+        assert_eq!(core.next_deadline(), None);
+        core.set_timeout(id, Duration::from_secs(2), 5_000);
+        assert_eq!(core.next_deadline(), Some(7_000));
+    }
+}
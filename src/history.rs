@@ -0,0 +1,143 @@
+use crate::store::ToastStore;
+use crate::time::format_clock;
+use crate::types::{DismissReason, HistoryEntry, ToastId};
+use dioxus::prelude::*;
+use std::collections::VecDeque;
+
+#[derive(Clone)]
+pub struct ToastHistoryHandle {
+    store: ToastStore,
+}
+
+impl ToastHistoryHandle {
+    pub fn entries(&self) -> Signal<VecDeque<HistoryEntry>> {
+        self.store.history()
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.store
+            .history()
+            .read()
+            .iter()
+            .filter(|entry| !entry.read)
+            .count()
+    }
+
+    pub fn mark_read(&self, id: ToastId) {
+        self.store.mark_read(id);
+    }
+
+    pub fn mark_all_read(&self) {
+        self.store.mark_all_read();
+    }
+
+    pub fn clear(&self) {
+        self.store.clear_history();
+    }
+}
+
+pub fn use_toast_history() -> ToastHistoryHandle {
+    let store = use_context::<ToastStore>();
+    ToastHistoryHandle { store }
+}
+
+impl DismissReason {
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            DismissReason::Timeout => "Timed out",
+            DismissReason::Click => "Dismissed",
+            DismissReason::CloseButton => "Closed",
+            DismissReason::Cleared => "Cleared",
+            DismissReason::Programmatic => "Removed",
+            DismissReason::Confirmed => "Confirmed",
+            DismissReason::Cancelled => "Cancelled",
+            DismissReason::Swipe => "Swiped away",
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToastHistoryProps {
+    #[props(optional)]
+    pub title: Option<String>,
+    #[props(optional)]
+    pub class: Option<String>,
+}
+
+#[component]
+pub fn ToastHistory(props: ToastHistoryProps) -> Element {
+    let history = use_toast_history();
+    let entries = history.entries().read().clone();
+    let unread = history.unread_count();
+    let is_empty = entries.is_empty();
+    let title = props.title.unwrap_or_else(|| "Notifications".to_string());
+    let class = props.class.unwrap_or_default();
+    let read_all = history.clone();
+    let clear_all = history.clone();
+
+    rsx! {
+        section { class: "toast-history {class}", "aria-label": "{title}",
+            header { class: "toast-history-header",
+                span { class: "toast-history-title", "{title}" }
+                if unread > 0 {
+                    span { class: "toast-history-badge", "{unread}" }
+                }
+                button {
+                    class: "toast-history-action",
+                    disabled: unread == 0,
+                    onclick: move |_| read_all.mark_all_read(),
+                    "Mark all read"
+                }
+                button {
+                    class: "toast-history-action",
+                    disabled: is_empty,
+                    onclick: move |_| clear_all.clear(),
+                    "Clear all"
+                }
+            }
+            if is_empty {
+                p { class: "toast-history-empty", "No notifications" }
+            }
+            ul { class: "toast-history-list",
+                for entry in entries {
+                    ToastHistoryItem { key: "{entry.id}", entry: entry.clone() }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ToastHistoryItemProps {
+    entry: HistoryEntry,
+}
+
+#[component]
+fn ToastHistoryItem(props: ToastHistoryItemProps) -> Element {
+    let entry = props.entry;
+    let read_class = if entry.read {
+        "toast-history-read"
+    } else {
+        "toast-history-unread"
+    };
+    let kind_class = use_context::<ToastStore>()
+        .options()
+        .presentation(&entry.kind)
+        .class_name;
+    let created = format_clock(entry.created_at);
+    let reason = entry.reason.label();
+    let id = entry.id;
+    let history = use_toast_history();
+
+    rsx! {
+        li {
+            class: "toast-history-entry {kind_class} {read_class}",
+            onclick: move |_| history.mark_read(id),
+            if let Some(title) = &entry.title {
+                div { class: "toast-title", "{title}" }
+            }
+            div { class: "toast-message", "{entry.message}" }
+            div { class: "toast-history-meta", "{created} · {reason}" }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use crate::store::ToastStore;
+use crate::types::{ToastKind, ToastRequest};
+use dioxus::prelude::*;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::StreamExt;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+/// A `tracing` layer that turns ERROR, WARN and INFO events into toasts.
+///
+/// The layer itself is `Send + Sync` and only forwards events over a channel;
+/// the matching [`ToastLayerEvents`] is drained into a `ToastStore` with
+/// [`use_toast_layer`]. Until that hook mounts, at most [`ToastLayer::BUFFER`]
+/// events are kept and later ones are dropped.
+#[derive(Clone)]
+pub struct ToastLayer {
+    sender: UnboundedSender<LayerEvent>,
+    pending: Arc<AtomicUsize>,
+    level: Level,
+    targets: Vec<String>,
+    field: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct ToastLayerEvents {
+    receiver: Arc<Mutex<Option<UnboundedReceiver<LayerEvent>>>>,
+    pending: Arc<AtomicUsize>,
+}
+
+struct LayerEvent {
+    kind: ToastKind,
+    message: String,
+    title: Option<String>,
+}
+
+impl ToastLayer {
+    /// Events kept while no `use_toast_layer` hook is draining them.
+    pub const BUFFER: usize = 64;
+
+    pub fn new() -> (Self, ToastLayerEvents) {
+        let (sender, receiver) = unbounded();
+        let pending = Arc::new(AtomicUsize::new(0));
+        let layer = Self {
+            sender,
+            pending: pending.clone(),
+            level: Level::INFO,
+            targets: Vec::new(),
+            field: None,
+        };
+        let events = ToastLayerEvents {
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+            pending,
+        };
+        (layer, events)
+    }
+
+    /// Most verbose level that still produces a toast. Defaults to `INFO`.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Only forward events whose target starts with `prefix`. Can be called
+    /// several times; with no target filter every target is accepted.
+    pub fn with_target(mut self, prefix: impl Into<String>) -> Self {
+        self.targets.push(prefix.into());
+        self
+    }
+
+    /// Only forward events that record `name`, e.g. `warn!(toast = true, ...)`.
+    /// A field recorded as `false` opts the event out.
+    pub fn with_field(mut self, name: impl Into<String>) -> Self {
+        self.field = Some(name.into());
+        self
+    }
+
+    fn accepts_target(&self, target: &str) -> bool {
+        self.targets.is_empty()
+            || self
+                .targets
+                .iter()
+                .any(|prefix| target.starts_with(prefix.as_str()))
+    }
+}
+
+impl<S: Subscriber> Layer<S> for ToastLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if *metadata.level() > self.level || !self.accepts_target(metadata.target()) {
+            return;
+        }
+        let kind = match *metadata.level() {
+            Level::ERROR => ToastKind::Error,
+            Level::WARN => ToastKind::Warning,
+            Level::INFO => ToastKind::Info,
+            _ => return,
+        };
+
+        let mut visitor = EventVisitor {
+            field: self.field.as_deref(),
+            matched: false,
+            message: None,
+            title: None,
+        };
+        event.record(&mut visitor);
+        if self.field.is_some() && !visitor.matched {
+            return;
+        }
+        let Some(message) = visitor.message else {
+            return;
+        };
+        let reserved = self
+            .pending
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |pending| {
+                (pending < Self::BUFFER).then_some(pending + 1)
+            });
+        if reserved.is_err() {
+            return;
+        }
+
+        let _ = self.sender.unbounded_send(LayerEvent {
+            kind,
+            message,
+            title: visitor.title,
+        });
+    }
+}
+
+impl ToastLayerEvents {
+    fn attach(&self) -> Option<Attached> {
+        let receiver = self.receiver.lock().ok()?.take()?;
+        Some(Attached {
+            events: self.clone(),
+            receiver: Some(receiver),
+        })
+    }
+}
+
+/// The receiver while a hook drains it. Handed back to the shared slot when
+/// the hook's task is dropped, so a remounted hook picks up where it left off.
+struct Attached {
+    events: ToastLayerEvents,
+    receiver: Option<UnboundedReceiver<LayerEvent>>,
+}
+
+impl Attached {
+    async fn next(&mut self) -> Option<LayerEvent> {
+        let event = self.receiver.as_mut()?.next().await?;
+        self.events.pending.fetch_sub(1, Ordering::AcqRel);
+        Some(event)
+    }
+}
+
+impl Drop for Attached {
+    fn drop(&mut self) {
+        if let Ok(mut slot) = self.events.receiver.lock() {
+            *slot = self.receiver.take();
+        }
+    }
+}
+
+/// Forwards events captured by a [`ToastLayer`] into the nearest `ToastProvider`.
+/// Only one hook drains a given [`ToastLayerEvents`] at a time; once it
+/// unmounts, the next one to mount takes over.
+pub fn use_toast_layer(events: ToastLayerEvents) {
+    let store = use_context::<ToastStore>();
+    use_hook(move || {
+        if let Some(mut attached) = events.attach() {
+            spawn(async move {
+                while let Some(event) = attached.next().await {
+                    let mut request = ToastRequest::new(event.kind, event.message);
+                    request.title = event.title;
+                    store.push(request);
+                }
+            });
+        }
+    });
+}
+
+struct EventVisitor<'a> {
+    field: Option<&'a str>,
+    matched: bool,
+    message: Option<String>,
+    title: Option<String>,
+}
+
+impl EventVisitor<'_> {
+    fn record_text(&mut self, field: &Field, value: String) {
+        match field.name() {
+            "message" => self.message = Some(value),
+            "title" => self.title = Some(value),
+            _ => {}
+        }
+        if self.field == Some(field.name()) {
+            self.matched = true;
+        }
+    }
+}
+
+impl Visit for EventVisitor<'_> {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if self.field == Some(field.name()) {
+            self.matched = value;
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_text(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record_text(field, format!("{value:?}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use tracing_subscriber::prelude::*;
+
+    #[test]
+    fn events_are_capped_until_drained_and_survive_a_reattach() {
+        let (layer, events) = ToastLayer::new();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(layer));
+        for index in 0..ToastLayer::BUFFER + 10 {
+            tracing::info!("event {index}");
+        }
+        assert_eq!(events.pending.load(Ordering::Acquire), ToastLayer::BUFFER);
+
+        let mut attached = events.attach().unwrap();
+        assert!(events.attach().is_none());
+        let first = attached.next().now_or_never().flatten().unwrap();
+        assert_eq!(first.message, "event 0");
+        drop(attached);
+
+        let mut attached = events.attach().unwrap();
+        let second = attached.next().now_or_never().flatten().unwrap();
+        assert_eq!(second.message, "event 1");
+        assert_eq!(
+            events.pending.load(Ordering::Acquire),
+            ToastLayer::BUFFER - 2
+        );
+    }
+}
@@ -1,9 +1,64 @@
+// Without `dioxus` only the headless core is built; the rendering helpers in
+// `types` go unused.
+#![cfg_attr(not(feature = "dioxus"), allow(dead_code))]
+
+#[cfg(feature = "dioxus")]
+mod broadcast;
+mod clock;
+#[cfg(feature = "fullstack")]
+mod fullstack;
+#[cfg(feature = "dioxus")]
+mod gesture;
+#[cfg(feature = "dioxus")]
 mod handle;
+mod headless;
+#[cfg(feature = "dioxus")]
+mod history;
+#[cfg(feature = "tracing")]
+mod layer;
+#[cfg(feature = "dioxus")]
+mod notify;
+#[cfg(feature = "persist")]
+mod persist;
+#[cfg(feature = "dioxus")]
 mod provider;
+#[cfg(feature = "dioxus")]
+mod rate_limit;
+#[cfg(feature = "dioxus")]
+mod registry;
+#[cfg(feature = "dioxus")]
+mod stack;
+#[cfg(feature = "dioxus")]
 mod store;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod time;
 mod types;
+#[cfg(feature = "dioxus")]
+mod visibility;
 
-pub use handle::{use_toast, ToastHandle};
-pub use provider::{ToastProvider, ToastProviderProps};
+pub use clock::{MockClock, SystemClock, ToastClock, ToastClockHandle};
+#[cfg(feature = "fullstack")]
+pub use fullstack::WithToasts;
+#[cfg(feature = "dioxus")]
+pub use handle::{use_toast, use_toast_in, ToastHandle};
+pub use headless::{CoreRequest, CoreToast, ToastCore};
+#[cfg(feature = "dioxus")]
+pub use history::{use_toast_history, ToastHistory, ToastHistoryHandle, ToastHistoryProps};
+#[cfg(feature = "tracing")]
+pub use layer::{use_toast_layer, ToastLayer, ToastLayerEvents};
+#[cfg(feature = "dioxus")]
+pub use notify::request_notification_permission;
+#[cfg(all(feature = "persist", target_arch = "wasm32"))]
+pub use persist::WebStorage;
+#[cfg(feature = "persist")]
+pub use persist::{MemoryStorage, PersistedToast, ToastPersistence, ToastStorage};
+#[cfg(feature = "dioxus")]
+pub use provider::{ToastProvider, ToastProviderProps, ToastViewport, ToastViewportProps};
+#[cfg(feature = "dioxus")]
 pub use store::ToastStore;
-pub use types::{Toast, ToastId, ToastKind, ToastOptions, ToastRequest};
+pub use types::{
+    AriaLive, ConfirmButtons, ConfirmOptions, CustomKind, DismissReason, HistoryEntry, KindOptions,
+    RateLimit, StackMode, SystemNotify, Toast, ToastCallback, ToastIcon, ToastId, ToastKind,
+    ToastMount, ToastOptions, ToastRequest,
+};
@@ -0,0 +1,85 @@
+use crate::types::{SystemNotify, Toast, ToastKind};
+use dioxus::core::{Runtime, ScopeId};
+use dioxus::prelude::*;
+use std::future::Future;
+
+/// Replies `true` once the notification is clicked and `false` as soon as it
+/// is closed or can't be shown, so the waiting task always finishes.
+const NOTIFY_JS: &str = r#"
+    const [title, body, tag, always] = await dioxus.recv();
+    if (!("Notification" in window)
+        || Notification.permission !== "granted"
+        || (!always && document.visibilityState !== "hidden")) {
+        dioxus.send(false);
+        return;
+    }
+    const notification = new Notification(title, { body, tag });
+    notification.onclick = () => {
+        window.focus();
+        dioxus.send(true);
+        notification.close();
+    };
+    notification.onclose = () => dioxus.send(false);
+"#;
+
+const PERMISSION_JS: &str = r#"
+    if (!("Notification" in window)) {
+        dioxus.send(false);
+        return;
+    }
+    const permission = Notification.permission === "default"
+        ? await Notification.requestPermission()
+        : Notification.permission;
+    dioxus.send(permission === "granted");
+"#;
+
+/// Asks the browser for permission to show system notifications and resolves
+/// to whether it was granted. Call it from a click handler: browsers ignore
+/// permission requests made without a user gesture, so the request is sent
+/// right away rather than when the future is first polled.
+pub fn request_notification_permission() -> impl Future<Output = bool> + 'static {
+    let mut eval = document::eval(PERMISSION_JS);
+    async move { eval.recv::<bool>().await.unwrap_or(false) }
+}
+
+/// Mirrors a toast as a Web Notification. Clicking it focuses the tab and
+/// runs the toast's `on_click` callback. The click listener runs in `scope`,
+/// the provider's scope, so it outlives the component that pushed the toast.
+pub(crate) fn show_system_notification(toast: &Toast, mode: SystemNotify, scope: ScopeId) {
+    let title = toast
+        .title
+        .clone()
+        .unwrap_or_else(|| default_title(&toast.kind));
+    let tag = format!("dioxus-toastr-{}", toast.id);
+    let mut eval = document::eval(NOTIFY_JS);
+    let payload = (
+        title,
+        toast.message.clone(),
+        tag,
+        mode == SystemNotify::Always,
+    );
+    if eval.send(payload).is_err() {
+        return;
+    }
+    let Some(on_click) = toast.on_click.clone() else {
+        return;
+    };
+    Runtime::current().in_scope(scope, || {
+        spawn(async move {
+            if matches!(eval.recv::<bool>().await, Ok(true)) {
+                on_click.call();
+            }
+        })
+    });
+}
+
+fn default_title(kind: &ToastKind) -> String {
+    match kind {
+        ToastKind::Error => "Error".to_string(),
+        ToastKind::Info => "Info".to_string(),
+        ToastKind::Success => "Success".to_string(),
+        ToastKind::Warning => "Warning".to_string(),
+        ToastKind::Loading => "Loading".to_string(),
+        ToastKind::Custom(name) => name.to_string(),
+    }
+}
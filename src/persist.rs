@@ -0,0 +1,190 @@
+use crate::types::{Toast, ToastKind, ToastRequest};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// A toast waiting to be shown again after a reload.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PersistedToast {
+    pub kind: ToastKind,
+    pub message: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Milliseconds left on the timer when saved, `None` for sticky toasts.
+    #[serde(default)]
+    pub remaining: Option<u64>,
+    /// Whether the timer was paused when saved, e.g. while hovered or in a
+    /// hidden tab. A paused toast keeps all of `remaining` after a reload.
+    #[serde(default)]
+    pub paused: bool,
+    /// Milliseconds since the Unix epoch.
+    #[serde(default)]
+    pub saved_at: u64,
+}
+
+impl PersistedToast {
+    /// The request that shows this toast again at `now` for the time it had
+    /// left, or `None` if that ran out while the page was gone.
+    pub(crate) fn into_request(self, now: u64) -> Option<ToastRequest> {
+        let time_out = match self.remaining {
+            None => Duration::ZERO,
+            Some(remaining) => {
+                let elapsed = if self.paused {
+                    0
+                } else {
+                    now.saturating_sub(self.saved_at)
+                };
+                let left = remaining.checked_sub(elapsed).filter(|left| *left > 0)?;
+                Duration::from_millis(left)
+            }
+        };
+        let mut request = ToastRequest::new(self.kind, self.message)
+            .with_timeout(time_out)
+            .persist();
+        request.title = self.title;
+        Some(request)
+    }
+}
+
+impl PersistedToast {
+    pub(crate) fn from_toast(toast: &Toast, now: u64) -> Self {
+        Self {
+            kind: toast.kind.clone(),
+            message: toast.message.clone(),
+            title: toast.title.clone(),
+            remaining: (!toast.is_sticky()).then(|| toast.remaining_at(now).as_millis() as u64),
+            paused: toast.is_paused(),
+            saved_at: now,
+        }
+    }
+}
+
+/// Storage backend for toasts created with `ToastRequest::persist()`.
+///
+/// `save` is called with every pending persisted toast whenever that set
+/// changes, and `load` once when the `ToastProvider` mounts.
+pub trait ToastStorage {
+    fn load(&self) -> Vec<PersistedToast>;
+    fn save(&self, toasts: &[PersistedToast]);
+}
+
+/// Shared handle to a [`ToastStorage`], passed to `ToastProvider { storage }`.
+#[derive(Clone)]
+pub struct ToastPersistence(Rc<dyn ToastStorage>);
+
+impl ToastPersistence {
+    pub fn new(storage: impl ToastStorage + 'static) -> Self {
+        Self(Rc::new(storage))
+    }
+
+    pub(crate) fn load(&self) -> Vec<PersistedToast> {
+        self.0.load()
+    }
+
+    pub(crate) fn save(&self, toasts: &[PersistedToast]) {
+        self.0.save(toasts);
+    }
+}
+
+impl PartialEq for ToastPersistence {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for ToastPersistence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToastPersistence").finish_non_exhaustive()
+    }
+}
+
+/// In-memory storage, mainly for tests. Clones share the same contents.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    toasts: Rc<RefCell<Vec<PersistedToast>>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn snapshot(&self) -> Vec<PersistedToast> {
+        self.toasts.borrow().clone()
+    }
+}
+
+impl ToastStorage for MemoryStorage {
+    fn load(&self) -> Vec<PersistedToast> {
+        self.snapshot()
+    }
+
+    fn save(&self, toasts: &[PersistedToast]) {
+        *self.toasts.borrow_mut() = toasts.to_vec();
+    }
+}
+
+/// `localStorage`/`sessionStorage` backed storage.
+#[cfg(target_arch = "wasm32")]
+#[derive(Clone, Debug)]
+pub struct WebStorage {
+    key: String,
+    local: bool,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl WebStorage {
+    const DEFAULT_KEY: &'static str = "dioxus-toastr";
+
+    pub fn local() -> Self {
+        Self {
+            key: Self::DEFAULT_KEY.to_string(),
+            local: true,
+        }
+    }
+
+    pub fn session() -> Self {
+        Self {
+            key: Self::DEFAULT_KEY.to_string(),
+            local: false,
+        }
+    }
+
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    fn storage(&self) -> Option<web_sys::Storage> {
+        let window = web_sys::window()?;
+        let storage = if self.local {
+            window.local_storage()
+        } else {
+            window.session_storage()
+        };
+        storage.ok().flatten()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl ToastStorage for WebStorage {
+    fn load(&self) -> Vec<PersistedToast> {
+        self.storage()
+            .and_then(|storage| storage.get_item(&self.key).ok().flatten())
+            .and_then(|value| serde_json::from_str(&value).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, toasts: &[PersistedToast]) {
+        let Some(storage) = self.storage() else {
+            return;
+        };
+        if toasts.is_empty() {
+            let _ = storage.remove_item(&self.key);
+        } else if let Ok(value) = serde_json::to_string(toasts) {
+            let _ = storage.set_item(&self.key, &value);
+        }
+    }
+}
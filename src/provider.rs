@@ -1,103 +1,518 @@
+use crate::clock::ToastClockHandle;
+use crate::gesture::{Swipe, SwipeAxis, POINTER_CAPTURE_JS};
+#[cfg(feature = "persist")]
+use crate::persist::ToastPersistence;
+use crate::registry::use_toast_registry;
+use crate::stack::{stack_layout, StackSlot};
 use crate::store::ToastStore;
-use crate::types::{Toast, ToastOptions};
+use crate::types::{
+    DismissReason, StackMode, Toast, ToastIcon, ToastId, ToastKind, ToastMount, ToastOptions,
+};
 use dioxus::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+const TICK: Duration = Duration::from_millis(100);
+const FADE_DURATION_MS: u64 = 300;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ToastProviderProps {
+    /// Makes this a scoped provider reached through `use_toast_in(name)`
+    /// instead of `use_toast()`. Read once when the provider mounts.
+    #[props(optional, into)]
+    pub name: Option<String>,
     #[props(optional)]
     pub options: Option<ToastOptions>,
+    /// Icons that replace the built-in icon of a kind.
+    #[props(optional)]
+    pub kind_icons: Option<HashMap<ToastKind, Element>>,
+    #[cfg(feature = "persist")]
+    #[props(optional)]
+    pub storage: Option<ToastPersistence>,
+    /// Time source for timeouts and animations; the wall clock by default.
+    #[props(optional)]
+    pub clock: Option<ToastClockHandle>,
+    /// Name of a `BroadcastChannel` shared with other tabs; see
+    /// `ToastRequest::broadcast`.
+    #[props(optional)]
+    pub broadcast_channel: Option<String>,
     pub children: Element,
 }
 
 #[component]
 pub fn ToastProvider(props: ToastProviderProps) -> Element {
     let options = props.options.clone().unwrap_or_default();
-    let store = use_context_provider(|| ToastStore::new(options.clone()));
+    let registry = use_toast_registry();
+    let store = use_hook(|| match &props.name {
+        Some(name) => {
+            let store = ToastStore::new(options.clone());
+            registry.register(name, store);
+            store
+        }
+        None => provide_context(ToastStore::new(options.clone())),
+    });
+    let name = use_hook(|| props.name.clone());
+    use_drop(move || {
+        if let Some(name) = &name {
+            registry.unregister(name, store);
+        }
+    });
     store.update_options(options.clone());
+    let system_clock = use_hook(ToastClockHandle::default);
+    store.set_clock(props.clock.clone().unwrap_or(system_clock));
+    store.set_kind_icons(props.kind_icons.clone().unwrap_or_default());
+    // `scoped` is fixed at mount, so the hook order stays stable. The default
+    // storage belongs to the page-level provider.
+    #[cfg(feature = "persist")]
+    {
+        let scoped = use_hook(|| props.name.is_some());
+        let default_storage = use_hook(default_storage).filter(|_| !scoped);
+        store.set_storage(props.storage.clone().or(default_storage));
+        use_hook(|| store.restore_persisted());
+    }
 
-    let toasts = store.toasts();
-    let render_items = toasts.read().clone();
-    let layout_class = if store.options().rtl { "toast-rtl" } else { "" };
-    let position_class = store.options().position_class;
-    let container_id = store.options().container_id;
-    static APP_CSS: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/toastr.min.css"));
+    crate::visibility::use_visibility_pause(store);
+    crate::broadcast::use_broadcast(store, props.broadcast_channel.clone());
 
+    let position_class = format!("toast-container {}", store.options().position_class);
+    // Every container needs its own id for the top-layer popover to find it.
+    let container_id = match &props.name {
+        Some(name) => format!("{}-{name}", store.options().container_id),
+        None => store.options().container_id.to_string(),
+    };
+    let inline = store.has_viewport();
+    #[cfg(feature = "fullstack")]
+    let hydration = rsx! { crate::fullstack::ToastHydration { store } };
+    #[cfg(not(feature = "fullstack"))]
+    let hydration = VNode::empty();
+    let top_layer = store.options().mount == ToastMount::TopLayer;
+    let z_index = store.options().z_index;
+    static APP_CSS: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/toastr.min.css"));
 
     rsx! {
         document::Style { "{APP_CSS}" }
-        div {
-            id: "{container_id}",
-            class: "{position_class} {layout_class}",
-            for toast in render_items {
-                ToastItem { toast: toast.clone() }
+        if !inline {
+            ToastContainer {
+                store,
+                id: container_id,
+                class: position_class,
+                top_layer,
+                z_index,
             }
         }
         {props.children}
+        {hydration}
     }
 }
 
 #[derive(Props, Clone, PartialEq)]
-struct ToastItemProps {
+pub struct ToastViewportProps {
+    /// Shows the toasts of the scoped `ToastProvider { name }` with this name
+    /// instead of the page-level one.
+    #[props(optional, into)]
+    pub name: Option<String>,
+    /// Positions the toasts absolutely in the nearest positioned ancestor
+    /// instead of in normal flow.
+    #[props(default)]
+    pub absolute: bool,
+    #[props(default)]
+    pub class: String,
+}
+
+/// Renders the provider's toasts inline, e.g. inside a card or form. While a
+/// viewport is mounted the provider skips its fixed-position container. A
+/// viewport naming a provider that isn't mounted renders nothing.
+#[component]
+pub fn ToastViewport(props: ToastViewportProps) -> Element {
+    let registry = use_toast_registry();
+    let page_store = use_hook(|| props.name.is_none().then(consume_context::<ToastStore>));
+    let store = match &props.name {
+        Some(name) => registry.get(name),
+        None => page_store,
+    };
+    let Some(store) = store else {
+        return rsx! {};
+    };
+
+    let placement = if props.absolute {
+        "toast-viewport toast-viewport-absolute"
+    } else {
+        "toast-viewport"
+    };
+    let class = format!("{placement} {}", props.class);
+
+    rsx! {
+        ViewportContainer { store, class }
+    }
+}
+
+#[component]
+fn ViewportContainer(store: ToastStore, class: String) -> Element {
+    use_hook(|| store.add_viewport());
+    use_drop(move || store.remove_viewport());
+
+    rsx! {
+        ToastContainer { store, class }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ToastContainerProps {
+    store: ToastStore,
+    #[props(optional)]
+    id: Option<String>,
+    class: String,
+    #[props(default)]
+    top_layer: bool,
+    #[props(optional)]
+    z_index: Option<i32>,
+}
+
+#[component]
+fn ToastContainer(props: ToastContainerProps) -> Element {
+    let store = props.store;
+    let render_items = store.toasts();
+    let layout_class = if store.options().rtl { "toast-rtl" } else { "" };
+    let mut heights = use_signal(HashMap::<ToastId, f64>::new);
+    let mut expanded = use_signal(|| false);
+    // Forget the measured height of every toast that has left.
+    use_effect(move || {
+        let toasts = store.toasts();
+        let gone = |id: &ToastId| !toasts.iter().any(|toast| toast.id == *id);
+        if heights.peek().keys().any(gone) {
+            heights.write().retain(|id, _| !gone(id));
+        }
+    });
+    let stacked = store.options().stack_mode == StackMode::Stacked;
+    let (stack_class, container_style, slots) = if stacked {
+        let ids: Vec<_> = render_items.iter().map(|toast| toast.id).collect();
+        let layout = stack_layout(
+            &ids,
+            &heights.read(),
+            store.options().newest_on_top,
+            *expanded.read(),
+            props.class.contains("toast-bottom"),
+        );
+        let stack_class = if *expanded.read() {
+            "toast-stacked toast-stack-expanded"
+        } else {
+            "toast-stacked"
+        };
+        let slots = layout
+            .slots
+            .into_iter()
+            .map(|slot| {
+                Some(StackSlot {
+                    style: slot.style,
+                    heights,
+                    forced_height: slot.forced_height,
+                })
+            })
+            .collect();
+        (
+            stack_class,
+            format!("height: {}px;", layout.container_height),
+            slots,
+        )
+    } else {
+        ("", String::new(), vec![None; render_items.len()])
+    };
+    let z_index_style = props
+        .z_index
+        .map(|z_index| format!(" z-index: {z_index};"))
+        .unwrap_or_default();
+    let popover = props.top_layer.then_some("manual");
+    use_effect(|| {
+        document::eval(POINTER_CAPTURE_JS);
+    });
+
+    // Re-open the popover whenever a toast arrives so it lands above any modal
+    // dialog or popover opened since.
+    let toast_count = render_items.len();
+    let top_layer = props.top_layer;
+    let id = props.id.clone();
+    use_effect(use_reactive!(|toast_count, top_layer, id| {
+        if let (true, Some(id), 1..) = (top_layer, id, toast_count) {
+            document::eval(&format!(
+                "const el = document.getElementById({id:?});
+                 if (el && el.showPopover) {{
+                     if (el.matches(':popover-open')) el.hidePopover();
+                     el.showPopover();
+                 }}"
+            ));
+        }
+    }));
+
+    rsx! {
+        div {
+            id: props.id,
+            class: "{props.class} {layout_class} {stack_class}",
+            style: "{container_style}{z_index_style}",
+            popover,
+            onmouseenter: move |_| expanded.set(true),
+            onmouseleave: move |_| expanded.set(false),
+            onfocusin: move |_| expanded.set(true),
+            onfocusout: move |_| expanded.set(false),
+            for (toast, stack) in render_items.into_iter().zip(slots) {
+                ToastItem { key: "{toast.id}", store, toast, stack }
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "persist", target_arch = "wasm32"))]
+fn default_storage() -> Option<ToastPersistence> {
+    Some(ToastPersistence::new(crate::persist::WebStorage::session()))
+}
+
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+fn default_storage() -> Option<ToastPersistence> {
+    None
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub(crate) struct ToastItemProps {
+    store: ToastStore,
     toast: Toast,
+    stack: Option<StackSlot>,
 }
 
 #[component]
-fn ToastItem(props: ToastItemProps) -> Element {
-    let store = use_context::<ToastStore>();
+pub(crate) fn ToastItem(props: ToastItemProps) -> Element {
+    let store = props.store;
     let options = store.options();
-    let close_button = options.close_button;
-    let tap_to_dismiss = options.tap_to_dismiss;
-    let progress_bar = options.progress_bar;
-    let class_name = format!("{} {}", options.toast_class, props.toast.kind.class_name());
-    let role = props.toast.kind.aria_role();
-    let timeout_ms = props.toast.time_out.as_millis() as u64;
-    let fade_duration_ms = 300u64;
+    let kind_options = options.resolve_kind(&props.toast.kind);
+    let close_button = kind_options.close_button;
+    let tap_to_dismiss = kind_options.tap_to_dismiss && props.toast.confirm.is_none();
+    let progress_bar = kind_options.progress_bar;
+    let presentation = options.presentation(&props.toast.kind);
+    let icon = if !options.show_icons {
+        ToastIcon::Hidden
+    } else {
+        match &props.toast.icon {
+            ToastIcon::Default => match store.kind_icons().read().get(&props.toast.kind) {
+                Some(icon) => ToastIcon::Custom(icon.clone()),
+                None if options.has_kind_icon(&props.toast.kind) => ToastIcon::Default,
+                None => ToastIcon::Hidden,
+            },
+            icon => icon.clone(),
+        }
+    };
+    let icon_class = match icon {
+        ToastIcon::Default => "",
+        ToastIcon::Hidden => " toast-no-icon",
+        ToastIcon::Custom(_) => " toast-custom-icon",
+    };
+    let kind_style = options
+        .custom_kind(&props.toast.kind)
+        .map(|kind| kind.style(matches!(icon, ToastIcon::Default)))
+        .unwrap_or_default();
+    let class_name = format!(
+        "{} {}{icon_class}",
+        options.toast_class, presentation.class_name
+    );
+    let role = presentation.aria_role;
+    let aria_live = presentation.aria_live.as_str();
+    let id = props.toast.id;
+    let timed = !props.toast.is_sticky();
+    // Progress bars and countdowns redraw every tick while the timer runs;
+    // otherwise a single wake-up starts the fade-out.
+    let ticks = timed && (progress_bar || options.countdown);
+    let deadline = props
+        .toast
+        .resumed_at
+        .map(|resumed_at| resumed_at + props.toast.remaining.as_millis() as u64);
+    let mut now = use_signal(|| store.now());
+    use_resource(use_reactive!(|deadline, ticks| async move {
+        now.set(store.now());
+        let Some(deadline) = deadline else {
+            return;
+        };
+        if ticks {
+            while store.now() < deadline {
+                store.clock().sleep(TICK).await;
+                now.set(store.now());
+            }
+        } else {
+            let fade_at = deadline.saturating_sub(FADE_DURATION_MS);
+            let wait = fade_at.saturating_sub(store.now());
+            store.clock().sleep(Duration::from_millis(wait)).await;
+            now.set(store.now());
+        }
+    }));
+    let remaining = props.toast.remaining_at(*now.read());
+    let remaining_ms = remaining.as_millis() as u64;
+    let remaining_fraction = if timed {
+        remaining.as_secs_f64() / props.toast.time_out.as_secs_f64()
+    } else {
+        0.0
+    };
     let is_bottom = options.position_class.contains("toast-bottom");
     let (toast_in, toast_out) = if is_bottom {
         ("toast-in-down", "toast-out-down")
     } else {
         ("toast-in", "toast-out")
     };
-    let toast_style = if timeout_ms > 0 {
-        let fade_out_delay_ms = timeout_ms.saturating_sub(fade_duration_ms);
+    let toast_style = if timed && !props.toast.is_paused() && remaining_ms <= FADE_DURATION_MS {
         format!(
-            "animation: {toast_in} {fade_duration_ms}ms ease-out, \
-             {toast_out} {fade_duration_ms}ms ease-in {fade_out_delay_ms}ms forwards;"
+            "animation: {toast_in} {FADE_DURATION_MS}ms ease-out, \
+             {toast_out} {FADE_DURATION_MS}ms ease-in forwards;"
         )
     } else {
-        String::new()
+        format!("animation: {toast_in} {FADE_DURATION_MS}ms ease-out;")
+    };
+    let countdown = (options.countdown && timed).then(|| remaining_ms.div_ceil(1000));
+
+    let swipe_enabled = options.swipe_to_dismiss;
+    let axis = SwipeAxis::for_position(&options.position_class);
+    let mut swipe = use_signal(|| None::<Swipe>);
+    let mut suppress_click = use_signal(|| false);
+    let swipe_style = match *swipe.read() {
+        Some(current) => format!("{} transition: none;", current.translate()),
+        None if swipe_enabled => format!("touch-action: {};", axis.touch_action()),
+        None => String::new(),
     };
 
-    let on_click_store = store.clone();
+    let stack_style = props
+        .stack
+        .as_ref()
+        .map(|slot| slot.style.clone())
+        .unwrap_or_default();
+    let stack_heights = props
+        .stack
+        .as_ref()
+        .filter(|slot| !slot.forced_height)
+        .map(|slot| slot.heights);
+
+    let on_click_callback = props.toast.on_click.clone();
     let on_click = move |_| {
+        if *suppress_click.peek() {
+            suppress_click.set(false);
+            return;
+        }
+        if let Some(callback) = &on_click_callback {
+            callback.call();
+        }
         if tap_to_dismiss {
-            on_click_store.remove(props.toast.id);
+            store.dismiss(id, DismissReason::Click);
+        }
+    };
+    let on_pointer_down = move |evt: PointerEvent| {
+        // Mouse drags select text; only touch and pen swipe.
+        if !swipe_enabled || swipe.peek().is_some() || evt.pointer_type() == "mouse" {
+            return;
+        }
+        let point = evt.client_coordinates();
+        swipe.set(Some(Swipe::start(
+            axis,
+            evt.pointer_id(),
+            point.x,
+            point.y,
+            store.now(),
+        )));
+        store.pause(id);
+    };
+    let on_pointer_move = move |evt: PointerEvent| {
+        let Some(mut current) = *swipe.peek() else {
+            return;
+        };
+        if current.pointer_id() == evt.pointer_id() {
+            let point = evt.client_coordinates();
+            current.update(point.x, point.y, store.now());
+            swipe.set(Some(current));
+        }
+    };
+    let on_pointer_end = move |evt: PointerEvent| {
+        let Some(current) = *swipe.peek() else {
+            return;
+        };
+        if current.pointer_id() != evt.pointer_id() {
+            return;
+        }
+        swipe.set(None);
+        suppress_click.set(current.moved());
+        if current.should_dismiss() {
+            store.dismiss(id, DismissReason::Swipe);
+        } else {
+            store.resume(id);
         }
     };
-    let close_store = store.clone();
 
     rsx! {
         div {
             class: "{class_name}",
+            "data-toast-swipe": swipe_enabled.then_some("true"),
             role: "{role}",
-            style: "{toast_style}",
+            "aria-live": "{aria_live}",
+            style: "{kind_style}{toast_style} {swipe_style} {stack_style}",
             onclick: on_click,
+            onpointerdown: on_pointer_down,
+            onpointermove: on_pointer_move,
+            onpointerup: on_pointer_end,
+            onpointercancel: on_pointer_end,
+            onmouseenter: move |_| store.pause(id),
+            onmouseleave: move |_| store.resume_extended(id),
+            onresize: move |evt| {
+                if let (Some(mut heights), Ok(size)) = (stack_heights, evt.get_border_box_size()) {
+                    if heights.peek().get(&id) != Some(&size.height) {
+                        heights.write().insert(id, size.height);
+                    }
+                }
+            },
             if close_button {
                 button {
                     class: "toast-close-button",
                     "aria-label": "close",
-                    onclick: move |_| close_store.remove(props.toast.id),
+                    onclick: move |evt| {
+                        evt.stop_propagation();
+                        store.dismiss(id, DismissReason::CloseButton);
+                    },
                     "×"
                 }
             }
+            if let ToastIcon::Custom(icon) = icon {
+                div { class: "toast-icon", "aria-hidden": "true", {icon} }
+            }
             if let Some(title) = &props.toast.title {
                 div { class: "toast-title", "{title}" }
             }
             div { class: "toast-message", "{props.toast.message}" }
-            if progress_bar {
+            if let Some(seconds) = countdown {
+                div { class: "toast-countdown", "aria-hidden": "true", "closes in {seconds}s" }
+            }
+            if let Some(buttons) = &props.toast.confirm {
+                div { class: "toast-actions",
+                    button {
+                        class: "toast-action toast-confirm",
+                        onclick: move |evt| {
+                            evt.stop_propagation();
+                            store.resolve_confirm(id, true);
+                        },
+                        "{buttons.confirm_label}"
+                    }
+                    button {
+                        class: "toast-action toast-cancel",
+                        onclick: move |evt| {
+                            evt.stop_propagation();
+                            store.resolve_confirm(id, false);
+                        },
+                        "{buttons.cancel_label}"
+                    }
+                }
+            }
+            if let Some(progress) = props.toast.progress {
+                div {
+                    class: "toast-progress toast-progress-determinate",
+                    role: "progressbar",
+                    "aria-valuemin": "0",
+                    "aria-valuemax": "100",
+                    "aria-valuenow": "{progress * 100.0:.0}",
+                    style: "width: {progress * 100.0}%;",
+                }
+            } else if progress_bar && timed {
                 div {
-                    class: "toast-progress",
-                    style: "animation: toast-progress linear {timeout_ms}ms;",
+                    class: "toast-progress toast-progress-timer",
+                    style: "width: {remaining_fraction * 100.0}%;",
                 }
             }
         }
@@ -0,0 +1,180 @@
+use crate::types::{RateLimit, ToastId};
+use std::collections::HashMap;
+
+/// Which bucket turned a toast away.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Throttled {
+    Global,
+    Key(String),
+}
+
+/// Token bucket holding up to `capacity` toasts and refilling at `capacity`
+/// per `RateLimit::per`.
+#[derive(Clone, Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: u64,
+}
+
+impl Bucket {
+    fn full(capacity: u32, now: u64) -> Self {
+        Self {
+            tokens: f64::from(capacity),
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, capacity: u32, limit: &RateLimit, now: u64) {
+        let capacity = f64::from(capacity);
+        let per = limit.per.as_millis() as f64;
+        let elapsed = now.saturating_sub(self.updated_at) as f64;
+        self.tokens = if per == 0.0 {
+            capacity
+        } else {
+            (self.tokens + elapsed * capacity / per).min(capacity)
+        };
+        self.updated_at = now;
+    }
+
+    fn has_token(&self) -> bool {
+        self.tokens >= 1.0
+    }
+}
+
+/// The global and per-key buckets behind `ToastOptions::rate_limit`, and the
+/// summary toast currently counting what each of them suppressed.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RateLimiter {
+    global: Option<Bucket>,
+    keys: HashMap<String, Bucket>,
+    summaries: HashMap<Throttled, (ToastId, u32)>,
+}
+
+impl RateLimiter {
+    /// Takes a token from both the key's bucket and the global one, or from
+    /// neither if either is empty.
+    pub fn admit(&mut self, limit: &RateLimit, key: String, now: u64) -> Result<(), Throttled> {
+        // Buckets untouched for a whole period are full again; drop them.
+        self.keys.retain(|_, bucket| {
+            now.saturating_sub(bucket.updated_at) < limit.per.as_millis() as u64
+        });
+
+        let global = self
+            .global
+            .get_or_insert_with(|| Bucket::full(limit.max, now));
+        global.refill(limit.max, limit, now);
+        let bucket = self
+            .keys
+            .entry(key.clone())
+            .or_insert_with(|| Bucket::full(limit.max_per_key, now));
+        bucket.refill(limit.max_per_key, limit, now);
+
+        if !bucket.has_token() {
+            return Err(Throttled::Key(key));
+        }
+        if !global.has_token() {
+            return Err(Throttled::Global);
+        }
+        bucket.tokens -= 1.0;
+        global.tokens -= 1.0;
+        Ok(())
+    }
+
+    /// Forgets summaries whose toast is no longer visible, so the next
+    /// suppressed toast starts a new count.
+    pub fn retain_summaries(&mut self, visible: impl Fn(ToastId) -> bool) {
+        self.summaries.retain(|_, (id, _)| visible(*id));
+    }
+
+    /// The summary toast for `throttled` and how many toasts it has counted.
+    pub fn summary(&self, throttled: &Throttled) -> Option<(ToastId, u32)> {
+        self.summaries.get(throttled).copied()
+    }
+
+    pub fn set_summary(&mut self, throttled: Throttled, id: ToastId, count: u32) {
+        self.summaries.insert(throttled, (id, count));
+    }
+}
+
+pub(crate) fn summary_message(throttled: &Throttled, count: u32) -> String {
+    let similar = match throttled {
+        Throttled::Global => "",
+        Throttled::Key(_) => "similar ",
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{count} {similar}notification{plural} suppressed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn limit(max: u32) -> RateLimit {
+        RateLimit::new(max, Duration::from_secs(10))
+    }
+
+    fn admit(limiter: &mut RateLimiter, limit: &RateLimit, key: &str, now: u64) -> bool {
+        limiter.admit(limit, key.to_string(), now).is_ok()
+    }
+
+    #[test]
+    fn global_bucket_admits_up_to_max() {
+        let mut limiter = RateLimiter::default();
+        let limit = limit(3);
+        for key in ["a", "b", "c"] {
+            assert!(admit(&mut limiter, &limit, key, 0));
+        }
+        assert_eq!(
+            limiter.admit(&limit, "d".to_string(), 0),
+            Err(Throttled::Global)
+        );
+    }
+
+    #[test]
+    fn key_bucket_admits_up_to_max_per_key() {
+        let mut limiter = RateLimiter::default();
+        let limit = limit(10).with_max_per_key(2);
+        assert!(admit(&mut limiter, &limit, "a", 0));
+        assert!(admit(&mut limiter, &limit, "a", 0));
+        assert_eq!(
+            limiter.admit(&limit, "a".to_string(), 0),
+            Err(Throttled::Key("a".to_string()))
+        );
+        assert!(admit(&mut limiter, &limit, "b", 0));
+    }
+
+    #[test]
+    fn buckets_refill_over_the_period() {
+        let mut limiter = RateLimiter::default();
+        let limit = limit(2);
+        assert!(admit(&mut limiter, &limit, "a", 0));
+        assert!(admit(&mut limiter, &limit, "b", 0));
+        assert!(!admit(&mut limiter, &limit, "c", 4_999));
+        // Half the period refills one of the two global tokens.
+        assert!(admit(&mut limiter, &limit, "c", 5_000));
+        assert!(!admit(&mut limiter, &limit, "d", 5_000));
+        assert!(admit(&mut limiter, &limit, "a", 10_000));
+    }
+
+    #[test]
+    fn a_throttled_key_does_not_spend_a_global_token() {
+        let mut limiter = RateLimiter::default();
+        let limit = limit(2);
+        assert!(admit(&mut limiter, &limit, "a", 0));
+        assert!(!admit(&mut limiter, &limit, "a", 0));
+        assert!(admit(&mut limiter, &limit, "b", 0));
+    }
+
+    #[test]
+    fn summary_message_counts_suppressed_toasts() {
+        assert_eq!(
+            summary_message(&Throttled::Global, 1),
+            "1 notification suppressed"
+        );
+        assert_eq!(
+            summary_message(&Throttled::Key("a".to_string()), 12),
+            "12 similar notifications suppressed"
+        );
+    }
+}
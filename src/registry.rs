@@ -0,0 +1,38 @@
+use crate::store::ToastStore;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// Named toast stores, kept at the root so a provider and the components
+/// pushing to it can live in unrelated subtrees. Each store belongs to the
+/// scope of the `ToastProvider { name }` that registered it.
+#[derive(Clone, Copy)]
+pub(crate) struct ToastRegistry {
+    stores: Signal<HashMap<String, ToastStore>>,
+}
+
+impl ToastRegistry {
+    /// The store of the mounted provider named `name`. Reading it subscribes
+    /// the caller, so it sees providers that mount later.
+    pub(crate) fn get(&self, name: &str) -> Option<ToastStore> {
+        self.stores.read().get(name).copied()
+    }
+
+    pub(crate) fn register(&self, name: &str, store: ToastStore) {
+        let mut stores = self.stores;
+        stores.write().insert(name.to_string(), store);
+    }
+
+    /// Forgets `name` unless another provider has taken it over since.
+    pub(crate) fn unregister(&self, name: &str, store: ToastStore) {
+        let mut stores = self.stores;
+        if stores.peek().get(name) == Some(&store) {
+            stores.write().remove(name);
+        }
+    }
+}
+
+pub(crate) fn use_toast_registry() -> ToastRegistry {
+    use_root_context(|| ToastRegistry {
+        stores: Signal::new_in_scope(HashMap::new(), ScopeId::ROOT),
+    })
+}
@@ -0,0 +1,195 @@
+use crate::types::ToastId;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+const VISIBLE_DEPTH: usize = 3;
+const PEEK: f64 = 10.0;
+const SCALE_STEP: f64 = 0.05;
+const GAP: f64 = 6.0;
+const FALLBACK_HEIGHT: f64 = 60.0;
+
+/// Measured toast heights, written by each `ToastItem` in stacked mode.
+pub(crate) type StackHeights = Signal<HashMap<ToastId, f64>>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct StackSlot {
+    pub style: String,
+    pub heights: StackHeights,
+    /// Whether `style` overrides the toast's height. Such a toast must not
+    /// record its size, or it would remember the forced height as its own.
+    pub forced_height: bool,
+}
+
+pub(crate) struct StackLayout {
+    pub container_height: f64,
+    pub slots: Vec<SlotLayout>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct SlotLayout {
+    pub style: String,
+    pub forced_height: bool,
+}
+
+/// Computes the deck layout for toasts in render order. The front toast is the
+/// newest one, which is the first item when `newest_on_top` and the last one
+/// otherwise.
+pub(crate) fn stack_layout(
+    ids: &[ToastId],
+    heights: &HashMap<ToastId, f64>,
+    newest_first: bool,
+    expanded: bool,
+    is_bottom: bool,
+) -> StackLayout {
+    let count = ids.len();
+    let height_of = |id: &ToastId| {
+        heights
+            .get(id)
+            .copied()
+            .filter(|height| *height > 0.0)
+            .unwrap_or(FALLBACK_HEIGHT)
+    };
+    let depth_of = |index: usize| {
+        if newest_first {
+            index
+        } else {
+            count - 1 - index
+        }
+    };
+    let mut by_depth: Vec<ToastId> = vec![0; count];
+    for (index, id) in ids.iter().enumerate() {
+        by_depth[depth_of(index)] = *id;
+    }
+    let front_height = by_depth.first().map(height_of).unwrap_or(0.0);
+
+    let mut offsets = vec![0.0; count];
+    let mut running = 0.0;
+    for (depth, id) in by_depth.iter().enumerate() {
+        offsets[depth] = if expanded {
+            running
+        } else {
+            depth.min(VISIBLE_DEPTH - 1) as f64 * PEEK
+        };
+        running += height_of(id) + GAP;
+    }
+
+    let container_height = if count == 0 {
+        0.0
+    } else if expanded {
+        running - GAP
+    } else {
+        front_height + (count.min(VISIBLE_DEPTH) - 1) as f64 * PEEK
+    };
+
+    let edge = if is_bottom { "bottom" } else { "top" };
+    let origin = if is_bottom { "top" } else { "bottom" };
+    let slots = (0..count)
+        .map(|index| {
+            let depth = depth_of(index);
+            let offset = offsets[depth];
+            let z_index = count - depth;
+            if expanded {
+                SlotLayout {
+                    style: format!("position: absolute; {edge}: {offset}px; z-index: {z_index};"),
+                    forced_height: false,
+                }
+            } else {
+                let scale = 1.0 - depth.min(VISIBLE_DEPTH) as f64 * SCALE_STEP;
+                let visibility = if depth < VISIBLE_DEPTH {
+                    "visible"
+                } else {
+                    "hidden"
+                };
+                let height = if depth == 0 {
+                    String::new()
+                } else {
+                    format!(" height: {front_height}px;")
+                };
+                SlotLayout {
+                    style: format!(
+                        "position: absolute; {edge}: {offset}px; z-index: {z_index}; \
+                         scale: {scale}; transform-origin: {origin} center; \
+                         visibility: {visibility};{height}"
+                    ),
+                    forced_height: depth != 0,
+                }
+            }
+        })
+        .collect();
+
+    StackLayout {
+        container_height,
+        slots,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heights(entries: &[(ToastId, f64)]) -> HashMap<ToastId, f64> {
+        entries.iter().copied().collect()
+    }
+
+    #[test]
+    fn empty_stack_has_no_height() {
+        let layout = stack_layout(&[], &HashMap::new(), true, false, false);
+        assert_eq!(layout.container_height, 0.0);
+        assert!(layout.slots.is_empty());
+    }
+
+    #[test]
+    fn collapsed_stack_peeks_the_next_two_toasts() {
+        let ids = [4, 3, 2, 1];
+        let layout = stack_layout(&ids, &heights(&[(4, 50.0)]), true, false, false);
+        assert_eq!(layout.container_height, 50.0 + 2.0 * PEEK);
+        assert!(layout.slots[0]
+            .style
+            .starts_with("position: absolute; top: 0px; z-index: 4;"));
+        assert!(layout.slots[1]
+            .style
+            .contains("top: 10px; z-index: 3; scale: 0.95;"));
+        assert!(layout.slots[2]
+            .style
+            .contains("top: 20px; z-index: 2; scale: 0.9;"));
+        assert!(layout.slots[2]
+            .style
+            .contains("visibility: visible; height: 50px;"));
+        assert!(layout.slots[3].style.contains("top: 20px; z-index: 1;"));
+        assert!(layout.slots[3].style.contains("visibility: hidden;"));
+        assert!(!layout.slots[0].forced_height);
+        assert!(layout.slots[1..].iter().all(|slot| slot.forced_height));
+    }
+
+    #[test]
+    fn oldest_first_puts_the_newest_toast_in_front() {
+        let ids = [1, 2];
+        let layout = stack_layout(&ids, &HashMap::new(), false, false, false);
+        assert!(layout.slots[1]
+            .style
+            .starts_with("position: absolute; top: 0px; z-index: 2;"));
+        assert!(layout.slots[0].style.contains("top: 10px; z-index: 1;"));
+        assert_eq!(layout.container_height, FALLBACK_HEIGHT + PEEK);
+    }
+
+    #[test]
+    fn expanded_stack_lists_toasts_by_measured_height() {
+        let ids = [3, 2, 1];
+        let measured = heights(&[(3, 40.0), (2, 0.0), (1, 70.0)]);
+        let layout = stack_layout(&ids, &measured, true, true, true);
+        assert!(layout.slots.iter().all(|slot| !slot.forced_height));
+        assert_eq!(
+            layout
+                .slots
+                .iter()
+                .map(|slot| slot.style.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "position: absolute; bottom: 0px; z-index: 3;",
+                "position: absolute; bottom: 46px; z-index: 2;",
+                "position: absolute; bottom: 112px; z-index: 1;",
+            ]
+        );
+        assert_eq!(layout.container_height, 40.0 + 60.0 + 70.0 + 2.0 * GAP);
+    }
+}
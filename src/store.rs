@@ -1,26 +1,94 @@
-use crate::types::{Toast, ToastId, ToastOptions, ToastRequest};
+use crate::clock::ToastClockHandle;
+use crate::headless::{CoreRequest, CoreToast, ToastCore};
+#[cfg(feature = "persist")]
+use crate::persist::{PersistedToast, ToastPersistence};
+use crate::rate_limit::{summary_message, RateLimiter, Throttled};
+use crate::types::{
+    ConfirmButtons, DismissReason, HistoryEntry, Toast, ToastCallback, ToastIcon, ToastId,
+    ToastKind, ToastOptions, ToastRequest,
+};
+use dioxus::core::Task;
+use dioxus::document::Eval;
 use dioxus::prelude::*;
+use futures_channel::oneshot;
+use std::collections::{HashMap, VecDeque};
 use std::time::Duration;
 
-#[cfg(target_arch = "wasm32")]
-use gloo_timers::future::TimeoutFuture;
+/// What a toast needs beyond the core's kind, text and timer state: how it
+/// renders and what happens when it is clicked or saved.
+#[derive(Clone, Debug, PartialEq)]
+struct ToastExtras {
+    persist: bool,
+    confirm: Option<ConfirmButtons>,
+    progress: Option<f32>,
+    icon: ToastIcon,
+    on_click: Option<ToastCallback>,
+}
 
-#[cfg(not(target_arch = "wasm32"))]
-use futures_timer::Delay;
+impl ToastExtras {
+    fn view(&self, toast: &CoreToast) -> Toast {
+        Toast {
+            id: toast.id,
+            kind: toast.kind.clone(),
+            message: toast.message.clone(),
+            title: toast.title.clone(),
+            time_out: toast.time_out,
+            created_at: toast.created_at,
+            shown_at: toast.shown_at,
+            remaining: toast.remaining,
+            resumed_at: toast.resumed_at,
+            persist: self.persist,
+            confirm: self.confirm.clone(),
+            progress: self.progress,
+            icon: self.icon.clone(),
+            on_click: self.on_click.clone(),
+        }
+    }
+}
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq)]
 pub struct ToastStore {
-    toasts: Signal<Vec<Toast>>,
-    next_id: Signal<ToastId>,
+    /// The scope that owns the signals and runs the wake-up task, so timers
+    /// keep going when the component that pushed a toast unmounts.
+    scope: ScopeId,
+    /// The single source of each toast's kind, text, order and timer.
+    core: Signal<ToastCore>,
+    extras: Signal<HashMap<ToastId, ToastExtras>>,
     options: Signal<ToastOptions>,
+    history: Signal<VecDeque<HistoryEntry>>,
+    confirmations: Signal<HashMap<ToastId, oneshot::Sender<bool>>>,
+    kind_icons: Signal<HashMap<ToastKind, Element>>,
+    /// The pending wake-up, cancelled whenever a new one is scheduled.
+    timer: Signal<Option<Task>>,
+    broadcast: Signal<Option<Eval>>,
+    viewports: Signal<usize>,
+    clock: Signal<ToastClockHandle>,
+    limiter: Signal<RateLimiter>,
+    #[cfg(feature = "persist")]
+    storage: Signal<Option<ToastPersistence>>,
 }
 
 impl ToastStore {
     pub fn new(options: ToastOptions) -> Self {
+        Self::new_in_scope(options, dioxus::core::current_scope_id())
+    }
+
+    pub(crate) fn new_in_scope(options: ToastOptions, scope: ScopeId) -> Self {
         Self {
-            toasts: Signal::new(Vec::new()),
-            next_id: Signal::new(1),
-            options: Signal::new(options),
+            scope,
+            core: Signal::new_in_scope(ToastCore::new(options.clone()), scope),
+            extras: Signal::new_in_scope(HashMap::new(), scope),
+            options: Signal::new_in_scope(options, scope),
+            history: Signal::new_in_scope(VecDeque::new(), scope),
+            confirmations: Signal::new_in_scope(HashMap::new(), scope),
+            kind_icons: Signal::new_in_scope(HashMap::new(), scope),
+            timer: Signal::new_in_scope(None, scope),
+            broadcast: Signal::new_in_scope(None, scope),
+            viewports: Signal::new_in_scope(0, scope),
+            clock: Signal::new_in_scope(ToastClockHandle::default(), scope),
+            limiter: Signal::new_in_scope(RateLimiter::default(), scope),
+            #[cfg(feature = "persist")]
+            storage: Signal::new_in_scope(None, scope),
         }
     }
 
@@ -29,80 +97,631 @@ impl ToastStore {
     }
 
     pub fn update_options(&self, options: ToastOptions) {
+        let mut core = self.core;
+        core.write().set_options(options.clone());
         let mut signal = self.options;
         signal.set(options);
     }
 
-    pub fn toasts(&self) -> Signal<Vec<Toast>> {
-        self.toasts
+    /// The visible toasts in display order. Subscribes the caller to changes.
+    pub fn toasts(&self) -> Vec<Toast> {
+        Self::view(&self.core.read(), &self.extras.read())
+    }
+
+    /// Like [`ToastStore::toasts`], without subscribing the caller.
+    pub fn peek_toasts(&self) -> Vec<Toast> {
+        Self::view(&self.core.peek(), &self.extras.peek())
+    }
+
+    fn view(core: &ToastCore, extras: &HashMap<ToastId, ToastExtras>) -> Vec<Toast> {
+        core.toasts()
+            .iter()
+            .filter_map(|toast| Some(extras.get(&toast.id)?.view(toast)))
+            .collect()
+    }
+
+    pub fn kind_icons(&self) -> Signal<HashMap<ToastKind, Element>> {
+        self.kind_icons
+    }
+
+    pub(crate) fn set_kind_icons(&self, icons: HashMap<ToastKind, Element>) {
+        let mut signal = self.kind_icons;
+        if *signal.peek() != icons {
+            signal.set(icons);
+        }
+    }
+
+    pub(crate) fn set_clock(&self, clock: ToastClockHandle) {
+        let mut signal = self.clock;
+        if *signal.peek() != clock {
+            signal.set(clock);
+            self.schedule();
+        }
+    }
+
+    /// The current time according to the provider's clock.
+    pub(crate) fn now(&self) -> u64 {
+        self.clock.peek().now()
+    }
+
+    pub(crate) fn clock(&self) -> ToastClockHandle {
+        self.clock.peek().clone()
+    }
+
+    /// Whether a `ToastViewport` currently renders this store's toasts.
+    pub(crate) fn has_viewport(&self) -> bool {
+        *self.viewports.read() > 0
+    }
+
+    pub(crate) fn add_viewport(&self) {
+        let mut viewports = self.viewports;
+        *viewports.write() += 1;
+    }
+
+    pub(crate) fn remove_viewport(&self) {
+        let mut viewports = self.viewports;
+        let count = *viewports.peek();
+        viewports.set(count.saturating_sub(1));
+    }
+
+    /// Swaps the joined broadcast channel, returning the previous one.
+    pub(crate) fn set_broadcast(&self, eval: Option<Eval>) -> Option<Eval> {
+        let mut signal = self.broadcast;
+        let previous = std::mem::replace(&mut *signal.write(), eval);
+        previous
+    }
+
+    pub fn history(&self) -> Signal<VecDeque<HistoryEntry>> {
+        self.history
     }
 
     pub fn clear(&self) {
-        let mut signal = self.toasts;
-        signal.set(Vec::new());
+        let mut core = self.core;
+        let removed = core.write().clear();
+        self.schedule();
+        let mut extras = self.extras;
+        extras.write().clear();
+        let mut confirmations = self.confirmations;
+        for (_, sender) in confirmations.write().drain() {
+            let _ = sender.send(false);
+        }
+        self.record_history(removed, DismissReason::Cleared);
+        #[cfg(feature = "persist")]
+        self.save_persisted();
     }
 
     pub fn remove(&self, id: ToastId) {
-        let mut signal = self.toasts;
-        let mut items = signal.write();
-        items.retain(|toast| toast.id != id);
+        self.dismiss(id, DismissReason::Programmatic);
     }
 
-    pub fn push(&self, request: ToastRequest) -> ToastId {
-        let options = self.options.read().clone();
-        let time_out = request.time_out.unwrap_or(options.time_out);
-        if options.prevent_duplicates {
-            let items = self.toasts.read();
-            let is_duplicate = items.iter().any(|toast| {
-                toast.message == request.message
-                    && toast.title == request.title
-                    && toast.kind == request.kind
+    pub fn dismiss(&self, id: ToastId, reason: DismissReason) {
+        let mut core = self.core;
+        let Some(removed) = core.write().dismiss(id) else {
+            return;
+        };
+        self.finish(removed, reason);
+        self.schedule();
+    }
+
+    /// Settles a toast the core has already removed: answers its confirm
+    /// sender, forgets its extras and records it in the history.
+    fn finish(&self, removed: CoreToast, reason: DismissReason) {
+        let mut extras = self.extras;
+        extras.write().remove(&removed.id);
+        let mut confirmations = self.confirmations;
+        if let Some(sender) = confirmations.write().remove(&removed.id) {
+            let _ = sender.send(reason == DismissReason::Confirmed);
+        }
+        self.record_history(vec![removed], reason);
+        #[cfg(feature = "persist")]
+        self.save_persisted();
+    }
+
+    /// Turns a visible toast into another kind, e.g. a finished loading
+    /// toast into a success toast. Returns `false` if it is already gone.
+    pub fn set_kind(&self, id: ToastId, kind: ToastKind) -> bool {
+        self.edit(|core| core.set_kind(id, kind))
+    }
+
+    pub fn set_message(&self, id: ToastId, message: impl Into<String>) -> bool {
+        let message = message.into();
+        self.edit(|core| core.set_message(id, message))
+    }
+
+    pub fn set_title(&self, id: ToastId, title: Option<String>) -> bool {
+        self.edit(|core| core.set_title(id, title))
+    }
+
+    /// Sets determinate progress, clamped to `0.0..=1.0`. Non-finite values
+    /// are rejected and leave the toast as it was.
+    pub fn set_progress(&self, id: ToastId, progress: f32) -> bool {
+        if !progress.is_finite() {
+            return false;
+        }
+        let mut extras = self.extras;
+        let mut extras = extras.write();
+        let Some(extras) = extras.get_mut(&id) else {
+            return false;
+        };
+        extras.progress = Some(progress.clamp(0.0, 1.0));
+        true
+    }
+
+    fn edit(&self, edit: impl FnOnce(&mut ToastCore) -> bool) -> bool {
+        let mut core = self.core;
+        let edited = edit(&mut core.write());
+        #[cfg(feature = "persist")]
+        if edited {
+            self.save_persisted();
+        }
+        edited
+    }
+
+    pub(crate) fn register_confirm(&self, id: ToastId, sender: oneshot::Sender<bool>) {
+        let mut confirmations = self.confirmations;
+        confirmations.write().insert(id, sender);
+    }
+
+    pub fn resolve_confirm(&self, id: ToastId, confirmed: bool) {
+        let reason = if confirmed {
+            DismissReason::Confirmed
+        } else {
+            DismissReason::Cancelled
+        };
+        self.dismiss(id, reason);
+    }
+
+    pub fn mark_read(&self, id: ToastId) {
+        let mut signal = self.history;
+        let mut history = signal.write();
+        if let Some(entry) = history.iter_mut().find(|entry| entry.id == id) {
+            entry.read = true;
+        }
+    }
+
+    pub fn mark_all_read(&self) {
+        let mut signal = self.history;
+        for entry in signal.write().iter_mut() {
+            entry.read = true;
+        }
+    }
+
+    pub fn clear_history(&self) {
+        let mut signal = self.history;
+        signal.set(VecDeque::new());
+    }
+
+    fn record_history(&self, removed: Vec<CoreToast>, reason: DismissReason) {
+        let limit = self.options.peek().history_limit;
+        if limit == 0 || removed.is_empty() {
+            return;
+        }
+        let dismissed_at = self.now();
+        let mut signal = self.history;
+        let mut history = signal.write();
+        for toast in removed {
+            history.push_front(HistoryEntry {
+                id: toast.id,
+                kind: toast.kind,
+                message: toast.message,
+                title: toast.title,
+                created_at: toast.created_at,
+                dismissed_at,
+                reason,
+                read: false,
             });
-            if is_duplicate {
+        }
+        history.truncate(limit);
+    }
+
+    /// Shows a toast. Returns `0` when `prevent_duplicates` or `rate_limit`
+    /// turned it away.
+    pub fn push(&self, request: ToastRequest) -> ToastId {
+        let limit = self.options.peek().rate_limit;
+        if let Some(limit) = limit.filter(|_| request.is_rate_limited()) {
+            let key = request.resolved_rate_key();
+            let mut limiter = self.limiter;
+            let admitted = limiter.write().admit(&limit, key, self.now());
+            if let Err(throttled) = admitted {
+                self.suppress(throttled, &request);
                 return 0;
             }
         }
+        self.insert(None, request)
+    }
 
-        let id = {
-            let mut signal = self.next_id;
-            let mut next_id = signal.write();
-            let id = *next_id;
-            *next_id += 1;
-            id
+    /// Counts a rate-limited toast on a summary toast, e.g. "12 similar
+    /// notifications suppressed", which stays up while the burst lasts.
+    fn suppress(&self, throttled: Throttled, request: &ToastRequest) {
+        let mut limiter = self.limiter;
+        let core = self.core;
+        limiter
+            .write()
+            .retain_summaries(|id| core.peek().get(id).is_some());
+        let summary = limiter.peek().summary(&throttled);
+        let (id, count) = match summary {
+            Some((id, count)) => {
+                let message = summary_message(&throttled, count + 1);
+                self.set_message(id, message);
+                let time_out = core.peek().get(id).map(|toast| toast.time_out);
+                if let Some(time_out) = time_out {
+                    self.set_timeout(id, time_out);
+                }
+                (id, count + 1)
+            }
+            None => {
+                let kind = match throttled {
+                    Throttled::Global => ToastKind::Info,
+                    Throttled::Key(_) => request.kind.clone(),
+                };
+                let id = self.insert(
+                    None,
+                    ToastRequest::new(kind, summary_message(&throttled, 1)),
+                );
+                (id, 1)
+            }
         };
+        if id != 0 {
+            limiter.write().set_summary(throttled, id, count);
+        }
+    }
+
+    /// Adds the toasts the server rendered under their server ids. Toasts the
+    /// client already shows under the same id, e.g. because its components
+    /// pushed them again while hydrating, are kept as they are, along with
+    /// their confirm senders and history. Restored toasts are not broadcast
+    /// or sent as system notifications a second time.
+    #[cfg(feature = "fullstack")]
+    pub(crate) fn hydrate(&self, mut toasts: Vec<(ToastId, ToastRequest)>) {
+        // Oldest first, so each lands where `newest_on_top` puts it.
+        toasts.sort_by_key(|(id, _)| *id);
+        for (id, mut request) in toasts {
+            if self.core.peek().get(id).is_some() {
+                continue;
+            }
+            request.broadcast = false;
+            request.system_notification = None;
+            self.insert(Some(id), request);
+        }
+    }
 
-        let toast = Toast {
-            id,
-            kind: request.kind,
-            message: request.message,
-            title: request.title,
-            time_out,
+    fn insert(&self, id: Option<ToastId>, request: ToastRequest) -> ToastId {
+        let mut core = self.core;
+        let pushed = {
+            let data = CoreRequest::from(&request);
+            let now = self.now();
+            let mut core = core.write();
+            match id {
+                Some(id) => core.push_with_id(id, &data, now),
+                None => core.push(&data, now),
+            }
+        };
+        let Some(id) = pushed else {
+            return 0;
         };
+        // Only toasts this tab accepted go out, so other tabs never see a
+        // duplicate that was rejected here.
+        if request.broadcast {
+            if let Some(eval) = *self.broadcast.peek() {
+                crate::broadcast::post(eval, &request);
+            }
+        }
 
-        {
-            let mut signal = self.toasts;
-            let mut items = signal.write();
-            if options.newest_on_top {
-                items.insert(0, toast);
-            } else {
-                items.push(toast);
+        let extras = ToastExtras {
+            persist: request.persist,
+            confirm: request.confirm,
+            progress: None,
+            icon: request.icon,
+            on_click: request.on_click,
+        };
+        if let Some(mode) = request.system_notification {
+            if let Some(toast) = self.core.peek().get(id) {
+                crate::notify::show_system_notification(&extras.view(toast), mode, self.scope);
             }
         }
+        {
+            let mut signal = self.extras;
+            signal.write().insert(id, extras);
+        }
+
+        #[cfg(feature = "persist")]
+        if request.persist {
+            self.save_persisted();
+        }
+
+        self.schedule();
+        id
+    }
 
-        if time_out != Duration::from_millis(0) {
-            let mut toasts = self.toasts;
+    /// Stops the auto-dismiss timer of a toast. Pauses nest: the timer only
+    /// runs again once every `pause` has been matched by a `resume`.
+    pub fn pause(&self, id: ToastId) {
+        self.with_timers(|core, now| {
+            core.pause(id, now);
+        });
+    }
+
+    pub fn resume(&self, id: ToastId) {
+        self.with_timers(|core, now| {
+            core.resume(id, now);
+        });
+    }
+
+    /// Resumes after a hover: the toast then only stays up for
+    /// `extended_time_out`, like toastr.
+    pub fn resume_extended(&self, id: ToastId) {
+        self.with_timers(|core, now| {
+            core.resume_extended(id, now);
+        });
+    }
+
+    /// Pauses every toast, including ones pushed before `resume_all`. Calling
+    /// it again while already paused has no effect.
+    pub fn pause_all(&self) {
+        self.with_timers(ToastCore::pause_all);
+    }
+
+    pub fn resume_all(&self) {
+        self.with_timers(ToastCore::resume_all);
+    }
+
+    /// Restarts the auto-dismiss timer with a new timeout, e.g. after turning a
+    /// loading toast into a success toast. `Duration::ZERO` makes it sticky.
+    pub fn set_timeout(&self, id: ToastId, time_out: Duration) {
+        self.with_timers(|core, now| {
+            core.set_timeout(id, time_out, now);
+        });
+        #[cfg(feature = "persist")]
+        self.save_persisted();
+    }
+
+    /// Applies a timer change to the core and reschedules the wake-up.
+    /// Storage is only rewritten when a persisted toast was paused or
+    /// resumed, so hovering other toasts never touches it.
+    fn with_timers(&self, change: impl FnOnce(&mut ToastCore, u64)) {
+        #[cfg(feature = "persist")]
+        let paused_before = self.persisted_paused();
+        let mut core = self.core;
+        change(&mut core.write(), self.now());
+        self.schedule();
+        #[cfg(feature = "persist")]
+        if self.persisted_paused() != paused_before {
+            self.save_persisted();
+        }
+    }
+
+    /// Sleeps until the core's next deadline, replacing the wake-up scheduled
+    /// before it. Server renders never start timers; the client starts them
+    /// once the page hydrates.
+    fn schedule(&self) {
+        let mut timer = self.timer;
+        if let Some(task) = timer.write().take() {
+            task.cancel();
+        }
+        if cfg!(feature = "server") {
+            return;
+        }
+        let Some(deadline) = self.core.peek().next_deadline() else {
+            return;
+        };
+        let store = *self;
+        let wake = self
+            .clock()
+            .sleep(Duration::from_millis(deadline.saturating_sub(self.now())));
+        let runtime = dioxus::core::Runtime::current();
+        let task = runtime.in_scope(self.scope, || {
             spawn(async move {
-                #[cfg(target_arch = "wasm32")]
-                TimeoutFuture::new(time_out.as_millis() as u32).await;
+                wake.await;
+                // This task is finishing; don't let `schedule` cancel it.
+                timer.write().take();
+                let mut core = store.core;
+                let expired = core.write().expire(store.now());
+                for toast in expired {
+                    store.finish(toast, DismissReason::Timeout);
+                }
+                store.schedule();
+            })
+        });
+        timer.set(Some(task));
+    }
 
-                #[cfg(not(target_arch = "wasm32"))]
-                Delay::new(time_out).await;
+    #[cfg(feature = "persist")]
+    pub(crate) fn set_storage(&self, storage: Option<ToastPersistence>) {
+        let mut signal = self.storage;
+        if *signal.peek() != storage {
+            signal.set(storage);
+        }
+    }
 
-                let mut items = toasts.write();
-                items.retain(|toast| toast.id != id);
-            });
+    #[cfg(feature = "persist")]
+    pub(crate) fn restore_persisted(&self) {
+        let Some(storage) = self.storage.peek().clone() else {
+            return;
+        };
+        let now = self.now();
+        for persisted in storage.load() {
+            let Some(request) = persisted.into_request(now) else {
+                continue;
+            };
+            // Skip the rate limit: a suppressed toast would be dropped from
+            // storage by the save below.
+            self.insert(None, request);
         }
+        self.save_persisted();
+    }
 
-        id
+    /// Which persisted toasts have a paused timer.
+    #[cfg(feature = "persist")]
+    fn persisted_paused(&self) -> Vec<(ToastId, bool)> {
+        self.peek_toasts()
+            .iter()
+            .filter(|toast| toast.persist)
+            .map(|toast| (toast.id, toast.is_paused()))
+            .collect()
+    }
+
+    #[cfg(feature = "persist")]
+    fn save_persisted(&self) {
+        let Some(storage) = self.storage.peek().clone() else {
+            return;
+        };
+        let now = self.now();
+        let pending: Vec<PersistedToast> = self
+            .peek_toasts()
+            .iter()
+            .filter(|toast| toast.persist)
+            .map(|toast| PersistedToast::from_toast(toast, now))
+            .collect();
+        storage.save(&pending);
+    }
+}
+
+#[cfg(all(test, any(feature = "fullstack", feature = "persist")))]
+mod tests {
+    use super::*;
+
+    fn app() -> Element {
+        rsx! {}
+    }
+
+    #[test]
+    #[cfg(feature = "fullstack")]
+    fn hydrate_keeps_client_toasts_and_adds_missing_ones() {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dom.in_scope(ScopeId::APP, || {
+            let store = ToastStore::new(ToastOptions::default());
+            let confirm =
+                crate::types::ConfirmOptions::default().into_request("Discard?".to_string());
+            let id = store.push(confirm.clone());
+            let (sender, _receiver) = oneshot::channel();
+            store.register_confirm(id, sender);
+
+            store.hydrate(vec![
+                (id, confirm),
+                (id + 1, ToastRequest::new(ToastKind::Info, "from server")),
+            ]);
+
+            let toasts = store.peek_toasts();
+            assert_eq!(toasts.len(), 2);
+            assert_eq!(toasts[0].id, id + 1);
+            assert_eq!(toasts[1].id, id);
+            assert!(store.confirmations.peek().contains_key(&id));
+            assert_eq!(
+                store.push(ToastRequest::new(ToastKind::Info, "next")),
+                id + 2
+            );
+        });
+    }
+
+    #[cfg(feature = "persist")]
+    #[derive(Clone, Default)]
+    struct CountingStorage(std::rc::Rc<std::cell::Cell<usize>>);
+
+    #[cfg(feature = "persist")]
+    impl crate::persist::ToastStorage for CountingStorage {
+        fn load(&self) -> Vec<PersistedToast> {
+            Vec::new()
+        }
+
+        fn save(&self, _toasts: &[PersistedToast]) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "persist")]
+    fn only_pausing_persisted_toasts_touches_storage() {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dom.in_scope(ScopeId::APP, || {
+            let saves = CountingStorage::default();
+            let store = ToastStore::new(ToastOptions::default());
+            store.set_storage(Some(ToastPersistence::new(saves.clone())));
+            let id = store.push(ToastRequest::new(ToastKind::Info, "Signed out").persist());
+            let other = store.push(ToastRequest::new(ToastKind::Info, "Saved"));
+            assert_eq!(saves.0.get(), 1);
+
+            store.pause(other);
+            store.resume(other);
+            assert_eq!(saves.0.get(), 1);
+
+            store.pause(id);
+            store.resume(id);
+            store.pause_all();
+            store.resume_all();
+            assert_eq!(saves.0.get(), 5);
+
+            store.set_message(id, "Signed out again");
+            store.dismiss(id, DismissReason::Programmatic);
+            assert_eq!(saves.0.get(), 7);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "persist")]
+    fn paused_toasts_keep_their_remaining_time_across_a_reload() {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dom.in_scope(ScopeId::APP, || {
+            let clock = crate::clock::MockClock::new(0);
+            let storage = crate::persist::MemoryStorage::new();
+            let store = ToastStore::new(ToastOptions::default());
+            store.set_clock(crate::clock::ToastClockHandle::new(clock.clone()));
+            store.set_storage(Some(ToastPersistence::new(storage.clone())));
+            let paused = store.push(
+                ToastRequest::new(ToastKind::Info, "Paused")
+                    .with_timeout(Duration::from_secs(5))
+                    .persist(),
+            );
+            store.push(
+                ToastRequest::new(ToastKind::Info, "Running")
+                    .with_timeout(Duration::from_secs(5))
+                    .persist(),
+            );
+            clock.advance(Duration::from_secs(1));
+            store.pause(paused);
+
+            clock.advance(Duration::from_secs(10));
+            let restored = ToastStore::new(ToastOptions::default());
+            restored.set_clock(crate::clock::ToastClockHandle::new(clock.clone()));
+            restored.set_storage(Some(ToastPersistence::new(storage.clone())));
+            restored.restore_persisted();
+
+            let toasts = restored.peek_toasts();
+            assert_eq!(toasts.len(), 1);
+            assert_eq!(toasts[0].message, "Paused");
+            assert_eq!(toasts[0].remaining_at(clock.now()), Duration::from_secs(4));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "persist")]
+    fn restored_toasts_skip_the_rate_limit() {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dom.in_scope(ScopeId::APP, || {
+            let storage = crate::persist::MemoryStorage::new();
+            let saved: Vec<_> = (0..3)
+                .map(|index| PersistedToast {
+                    kind: ToastKind::Info,
+                    message: format!("Signed out {index}"),
+                    title: None,
+                    remaining: None,
+                    paused: false,
+                    saved_at: 0,
+                })
+                .collect();
+            crate::persist::ToastStorage::save(&storage, &saved);
+            let store = ToastStore::new(ToastOptions {
+                rate_limit: Some(crate::types::RateLimit::new(1, Duration::from_secs(10))),
+                ..ToastOptions::default()
+            });
+            store.set_storage(Some(ToastPersistence::new(storage.clone())));
+
+            store.restore_persisted();
+            assert_eq!(store.peek_toasts().len(), 3);
+            assert_eq!(storage.snapshot().len(), 3);
+        });
     }
 }
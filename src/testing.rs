@@ -0,0 +1,246 @@
+//! Helpers for checking toasts in component tests, behind the `testing`
+//! feature. Mount the app under [`TestToastProvider`], which runs on a
+//! [`MockClock`] starting at `0`, then assert on what is visible:
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_toastr::testing::{advance, toasts, TestToastProvider};
+//! use dioxus_toastr::{use_toast, ToastKind};
+//! use std::time::Duration;
+//!
+//! #[component]
+//! fn SaveButton() -> Element {
+//!     let toast = use_toast();
+//!     use_hook(|| toast.error("Saving failed"));
+//!     rsx! {}
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| rsx! { TestToastProvider { SaveButton {} } });
+//! dom.rebuild_in_place();
+//! toasts().expect_one(ToastKind::Error).with_message_containing("failed");
+//!
+//! advance(&mut dom, Duration::from_secs(5));
+//! toasts().expect_none();
+//! ```
+//!
+//! The mounted provider is tracked per thread, so tests running in parallel
+//! don't see each other's toasts.
+
+use crate::clock::{MockClock, ToastClockHandle};
+use crate::provider::{ToastItem, ToastProvider};
+use crate::store::ToastStore;
+use crate::types::{Toast, ToastKind, ToastOptions, ToastRequest};
+use dioxus::core::NoOpMutations;
+use dioxus::prelude::*;
+use futures_util::FutureExt;
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+thread_local! {
+    static STORE: Cell<Option<ToastStore>> = const { Cell::new(None) };
+    static CLOCK: RefCell<Option<MockClock>> = const { RefCell::new(None) };
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TestToastProviderProps {
+    #[props(optional)]
+    pub options: Option<ToastOptions>,
+    pub children: Element,
+}
+
+/// A `ToastProvider` driven by a [`MockClock`] whose toasts can be read with
+/// [`toasts()`] from outside the `VirtualDom`.
+#[component]
+pub fn TestToastProvider(props: TestToastProviderProps) -> Element {
+    let clock = use_hook(|| {
+        let clock = MockClock::new(0);
+        CLOCK.with_borrow_mut(|mounted| *mounted = Some(clock.clone()));
+        ToastClockHandle::new(clock)
+    });
+
+    rsx! {
+        ToastProvider {
+            options: props.options.clone().unwrap_or_default(),
+            clock,
+            ToastProbe {}
+            {props.children}
+        }
+    }
+}
+
+#[component]
+fn ToastProbe() -> Element {
+    let store = use_context::<ToastStore>();
+    use_hook(|| STORE.set(Some(store)));
+    VNode::empty()
+}
+
+/// The clock of the [`TestToastProvider`] mounted on this thread.
+#[track_caller]
+pub fn clock() -> MockClock {
+    CLOCK
+        .with_borrow(Clone::clone)
+        .expect("no TestToastProvider is mounted on this thread")
+}
+
+/// The toasts visible right now in the [`TestToastProvider`] mounted on this
+/// thread.
+#[track_caller]
+pub fn toasts() -> ToastAssertions {
+    let store = STORE
+        .get()
+        .expect("no TestToastProvider is mounted on this thread");
+    ToastAssertions {
+        toasts: store.peek_toasts(),
+    }
+}
+
+/// Runs pending tasks and re-renders until the `VirtualDom` has nothing left
+/// to do without time passing.
+pub fn settle(dom: &mut VirtualDom) {
+    while dom.wait_for_work().now_or_never().is_some() {
+        dom.render_immediate(&mut NoOpMutations);
+    }
+}
+
+/// Moves the test clock forward and lets every timer that ran out fire.
+#[track_caller]
+pub fn advance(dom: &mut VirtualDom, duration: Duration) {
+    clock().advance(duration);
+    settle(dom);
+}
+
+/// A snapshot of visible toasts with assertion helpers. Failed assertions
+/// panic with the list of toasts that were visible.
+#[derive(Clone, Debug)]
+pub struct ToastAssertions {
+    toasts: Vec<Toast>,
+}
+
+impl ToastAssertions {
+    /// Visible toasts in display order.
+    pub fn all(&self) -> &[Toast] {
+        &self.toasts
+    }
+
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    #[track_caller]
+    pub fn expect_none(&self) {
+        if !self.toasts.is_empty() {
+            panic!("expected no toasts, found {}", self.describe());
+        }
+    }
+
+    #[track_caller]
+    pub fn expect_count(&self, count: usize) -> &Self {
+        if self.toasts.len() != count {
+            panic!("expected {count} toasts, found {}", self.describe());
+        }
+        self
+    }
+
+    /// Asserts that exactly one visible toast has `kind` and returns it for
+    /// further checks.
+    #[track_caller]
+    pub fn expect_one(&self, kind: ToastKind) -> ToastAssertion {
+        let mut matching = self.toasts.iter().filter(|toast| toast.kind == kind);
+        match (matching.next(), matching.next()) {
+            (Some(toast), None) => ToastAssertion {
+                toast: toast.clone(),
+            },
+            _ => panic!(
+                "expected exactly one {kind:?} toast, found {}",
+                self.describe()
+            ),
+        }
+    }
+
+    fn describe(&self) -> String {
+        if self.toasts.is_empty() {
+            return "none".to_string();
+        }
+        self.toasts
+            .iter()
+            .map(|toast| format!("{:?}: {:?}", toast.kind, toast.message))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// One toast picked by [`ToastAssertions::expect_one`].
+#[derive(Clone, Debug)]
+pub struct ToastAssertion {
+    toast: Toast,
+}
+
+impl ToastAssertion {
+    pub fn toast(&self) -> &Toast {
+        &self.toast
+    }
+
+    #[track_caller]
+    pub fn with_message(&self, message: &str) -> &Self {
+        if self.toast.message != message {
+            panic!(
+                "expected message {message:?}, found {:?}",
+                self.toast.message
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    pub fn with_message_containing(&self, needle: &str) -> &Self {
+        if !self.toast.message.contains(needle) {
+            panic!(
+                "expected a message containing {needle:?}, found {:?}",
+                self.toast.message
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    pub fn with_title(&self, title: &str) -> &Self {
+        if self.toast.title.as_deref() != Some(title) {
+            panic!("expected title {title:?}, found {:?}", self.toast.title);
+        }
+        self
+    }
+}
+
+/// Renders the `ToastItem` markup for `request` with default options, for
+/// snapshot tests. Time stands still at the moment the toast is shown.
+pub fn render_toast(request: ToastRequest) -> String {
+    render_toast_with(request, ToastOptions::default())
+}
+
+pub fn render_toast_with(request: ToastRequest, options: ToastOptions) -> String {
+    let mut dom =
+        VirtualDom::new_with_props(ToastSnapshot, ToastSnapshotProps { request, options });
+    dom.rebuild_in_place();
+    dioxus::ssr::render(&dom)
+}
+
+#[component]
+fn ToastSnapshot(request: ToastRequest, options: ToastOptions) -> Element {
+    let store = use_hook(|| {
+        let store = ToastStore::new(options.clone());
+        store.set_clock(ToastClockHandle::new(MockClock::new(0)));
+        store.push(request.clone());
+        store
+    });
+
+    rsx! {
+        for toast in store.toasts() {
+            ToastItem { key: "{toast.id}", store, toast, stack: None }
+        }
+    }
+}
@@ -1,57 +1,373 @@
+#[cfg(feature = "dioxus")]
+use dioxus::prelude::Element;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
 use std::time::Duration;
 
 pub type ToastId = u64;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Serializes as its [`name`](ToastKind::name), e.g. `"error"` or
+/// `"custom:billing"`, so it also works as a JSON map key.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "String", from = "String"))]
 pub enum ToastKind {
     Error,
     Info,
     Success,
     Warning,
+    Loading,
+    /// A kind registered through `ToastOptions::custom_kinds`.
+    Custom(Cow<'static, str>),
 }
 
 impl ToastKind {
-    pub(crate) fn class_name(self) -> &'static str {
+    pub fn custom(name: impl Into<Cow<'static, str>>) -> Self {
+        ToastKind::Custom(name.into())
+    }
+
+    /// Plain string form: `"error"`, `"info"`, ... or `"custom:{name}"`.
+    pub fn name(&self) -> Cow<'static, str> {
+        match self {
+            ToastKind::Error => Cow::Borrowed("error"),
+            ToastKind::Info => Cow::Borrowed("info"),
+            ToastKind::Success => Cow::Borrowed("success"),
+            ToastKind::Warning => Cow::Borrowed("warning"),
+            ToastKind::Loading => Cow::Borrowed("loading"),
+            ToastKind::Custom(name) => Cow::Owned(format!("custom:{name}")),
+        }
+    }
+
+    /// Parses [`name`](ToastKind::name). Unknown names fall back to `Info`.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "error" => ToastKind::Error,
+            "success" => ToastKind::Success,
+            "warning" => ToastKind::Warning,
+            "loading" => ToastKind::Loading,
+            _ => match name.strip_prefix("custom:") {
+                Some(custom) => ToastKind::custom(custom.to_string()),
+                None => ToastKind::Info,
+            },
+        }
+    }
+
+    pub(crate) fn class_name(&self) -> Cow<'static, str> {
         match self {
-            ToastKind::Error => "toast-error",
-            ToastKind::Info => "toast-info",
-            ToastKind::Success => "toast-success",
-            ToastKind::Warning => "toast-warning",
+            ToastKind::Error => Cow::Borrowed("toast-error"),
+            ToastKind::Info => Cow::Borrowed("toast-info"),
+            ToastKind::Success => Cow::Borrowed("toast-success"),
+            ToastKind::Warning => Cow::Borrowed("toast-warning"),
+            ToastKind::Loading => Cow::Borrowed("toast-loading"),
+            ToastKind::Custom(name) => Cow::Owned(format!("toast-{name}")),
         }
     }
 
-    pub(crate) fn aria_role(self) -> &'static str {
+    pub(crate) fn aria_role(&self) -> &'static str {
         match self {
             ToastKind::Error => "alert",
             ToastKind::Info => "status",
             ToastKind::Success => "status",
             ToastKind::Warning => "alert",
+            ToastKind::Loading => "status",
+            ToastKind::Custom(_) => "status",
+        }
+    }
+
+    pub(crate) fn aria_live(&self) -> AriaLive {
+        match self.aria_role() {
+            "alert" => AriaLive::Assertive,
+            _ => AriaLive::Polite,
         }
     }
 }
 
+impl fmt::Display for ToastKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
+
+impl From<ToastKind> for String {
+    fn from(kind: ToastKind) -> Self {
+        kind.name().into_owned()
+    }
+}
+
+impl From<String> for ToastKind {
+    fn from(name: String) -> Self {
+        ToastKind::from_name(&name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AriaLive {
+    Off,
+    Polite,
+    Assertive,
+}
+
+impl AriaLive {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            AriaLive::Off => "off",
+            AriaLive::Polite => "polite",
+            AriaLive::Assertive => "assertive",
+        }
+    }
+}
+
+/// Presentation and defaults for a `ToastKind::Custom` kind.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CustomKind {
+    pub name: Cow<'static, str>,
+    pub class_name: Cow<'static, str>,
+    pub aria_role: Cow<'static, str>,
+    pub aria_live: AriaLive,
+    #[cfg_attr(feature = "serde", serde(default, with = "millis::option"))]
+    pub time_out: Option<Duration>,
+    /// CSS image, e.g. `url("data:image/svg+xml;...")`, shown like the built-in icons.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub icon: Option<Cow<'static, str>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub background_color: Option<Cow<'static, str>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub text_color: Option<Cow<'static, str>>,
+}
+
+impl CustomKind {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        let name = name.into();
+        Self::from_preset(name.clone(), &ToastKind::Custom(name))
+    }
+
+    /// Starts from the class, ARIA role and politeness of a built-in kind, so
+    /// e.g. a "security" kind can look and announce itself like `Error`.
+    pub fn from_preset(name: impl Into<Cow<'static, str>>, preset: &ToastKind) -> Self {
+        let name = name.into();
+        let class_name = match preset {
+            ToastKind::Custom(_) => ToastKind::Custom(name.clone()).class_name(),
+            preset => Cow::Owned(format!("{} toast-{name}", preset.class_name())),
+        };
+        Self {
+            name,
+            class_name,
+            aria_role: Cow::Borrowed(preset.aria_role()),
+            aria_live: preset.aria_live(),
+            time_out: None,
+            icon: None,
+            background_color: None,
+            text_color: None,
+        }
+    }
+
+    pub fn kind(&self) -> ToastKind {
+        ToastKind::Custom(self.name.clone())
+    }
+
+    pub fn with_class(mut self, class_name: impl Into<Cow<'static, str>>) -> Self {
+        self.class_name = class_name.into();
+        self
+    }
+
+    pub fn with_role(mut self, role: impl Into<Cow<'static, str>>, live: AriaLive) -> Self {
+        self.aria_role = role.into();
+        self.aria_live = live;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.time_out = Some(timeout);
+        self
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<Cow<'static, str>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn with_colors(
+        mut self,
+        background: impl Into<Cow<'static, str>>,
+        text: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.background_color = Some(background.into());
+        self.text_color = Some(text.into());
+        self
+    }
+
+    pub(crate) fn has_icon(&self) -> bool {
+        const BUILT_IN: [&str; 5] = [
+            "toast-error",
+            "toast-info",
+            "toast-success",
+            "toast-warning",
+            "toast-loading",
+        ];
+        self.icon.is_some()
+            || self
+                .class_name
+                .split_whitespace()
+                .any(|class| BUILT_IN.contains(&class))
+    }
+
+    /// Inline style for toasts of this kind. Kept out of any `<style>` sheet,
+    /// so a color or icon value can only ever affect the toast itself. The
+    /// icon is marked `!important` to win over the preset's built-in icon,
+    /// and left out when the toast shows no icon or one of its own.
+    pub(crate) fn style(&self, show_icon: bool) -> String {
+        let mut style = String::new();
+        if let Some(background) = &self.background_color {
+            style.push_str(&format!("background-color: {background};"));
+        }
+        if let Some(text) = &self.text_color {
+            style.push_str(&format!("color: {text};"));
+        }
+        if let Some(icon) = self.icon.as_ref().filter(|_| show_icon) {
+            style.push_str(&format!("background-image: {icon} !important;"));
+        }
+        style
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StackMode {
+    /// Every toast is laid out below the previous one.
+    #[default]
+    List,
+    /// Toasts overlap like a deck and fan out on hover or focus.
+    Stacked,
+}
+
+/// Where the toast container is painted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ToastMount {
+    /// In place, next to the provider's children.
+    #[default]
+    InPlace,
+    /// In the browser top layer through the Popover API, above every stacking
+    /// context, clipping ancestor and previously opened modal dialog. The
+    /// element stays in the Dioxus tree because events are delegated to its
+    /// root.
+    TopLayer,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DismissReason {
+    Timeout,
+    Click,
+    CloseButton,
+    Cleared,
+    Programmatic,
+    Confirmed,
+    Cancelled,
+    Swipe,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ToastOptions {
     pub tap_to_dismiss: bool,
-    pub toast_class: &'static str,
-    pub container_id: &'static str,
-    pub position_class: &'static str,
+    pub toast_class: Cow<'static, str>,
+    /// Id of the provider's fixed container; a scoped provider appends
+    /// `-{name}`. Styles target the `toast-container` class instead.
+    pub container_id: Cow<'static, str>,
+    pub position_class: Cow<'static, str>,
+    #[cfg_attr(feature = "serde", serde(with = "millis"))]
     pub time_out: Duration,
+    #[cfg_attr(feature = "serde", serde(with = "millis"))]
     pub extended_time_out: Duration,
     pub close_button: bool,
     pub newest_on_top: bool,
     pub prevent_duplicates: bool,
     pub progress_bar: bool,
     pub rtl: bool,
+    pub history_limit: usize,
+    pub custom_kinds: Vec<CustomKind>,
+    pub kind_defaults: HashMap<ToastKind, KindOptions>,
+    pub show_icons: bool,
+    pub swipe_to_dismiss: bool,
+    pub stack_mode: StackMode,
+    /// Show a "closes in 4s" label under the message of timed toasts.
+    pub countdown: bool,
+    /// Pause every timer while the browser tab is hidden.
+    pub pause_on_page_hidden: bool,
+    /// Pause every timer while the window does not have focus.
+    pub pause_on_window_blur: bool,
+    pub mount: ToastMount,
+    /// Overrides the container's `z-index` (999999 by default).
+    pub z_index: Option<i32>,
+    /// Suppresses bursts of toasts and shows a running count of what was
+    /// left out instead. Confirm and loading toasts are exempt.
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// How many toasts `ToastStore::push` lets through in a burst. Each bucket
+/// holds up to its limit and refills at that many toasts per `per`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RateLimit {
+    /// Toasts of any kind per `per`.
+    pub max: u32,
+    #[cfg_attr(feature = "serde", serde(with = "millis"))]
+    pub per: Duration,
+    /// Toasts with the same `ToastRequest::rate_key` per `per`; 1 by default,
+    /// so repeats of one message collapse into a single toast.
+    #[cfg_attr(feature = "serde", serde(default = "RateLimit::default_max_per_key"))]
+    pub max_per_key: u32,
+}
+
+impl RateLimit {
+    pub fn new(max: u32, per: Duration) -> Self {
+        Self {
+            max,
+            per,
+            max_per_key: Self::default_max_per_key(),
+        }
+    }
+
+    pub fn with_max_per_key(mut self, max_per_key: u32) -> Self {
+        self.max_per_key = max_per_key;
+        self
+    }
+
+    fn default_max_per_key() -> u32 {
+        1
+    }
+}
+
+/// Per-kind overrides for `ToastOptions`. `None` falls back to the custom kind
+/// settings, then to the global value.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct KindOptions {
+    #[cfg_attr(feature = "serde", serde(with = "millis::option"))]
+    pub time_out: Option<Duration>,
+    #[cfg_attr(feature = "serde", serde(with = "millis::option"))]
+    pub extended_time_out: Option<Duration>,
+    pub close_button: Option<bool>,
+    pub progress_bar: Option<bool>,
+    pub tap_to_dismiss: Option<bool>,
 }
 
 impl Default for ToastOptions {
     fn default() -> Self {
         Self {
             tap_to_dismiss: true,
-            toast_class: "toast",
-            container_id: "toast-container",
-            position_class: "toast-top-right",
+            toast_class: Cow::Borrowed("toast"),
+            container_id: Cow::Borrowed("toast-container"),
+            position_class: Cow::Borrowed("toast-top-right"),
             time_out: Duration::from_millis(5000),
             extended_time_out: Duration::from_millis(1000),
             close_button: false,
@@ -59,16 +375,161 @@ impl Default for ToastOptions {
             prevent_duplicates: false,
             progress_bar: false,
             rtl: false,
+            history_limit: 50,
+            custom_kinds: Vec::new(),
+            kind_defaults: HashMap::new(),
+            show_icons: true,
+            swipe_to_dismiss: true,
+            stack_mode: StackMode::List,
+            countdown: false,
+            pause_on_page_hidden: false,
+            pause_on_window_blur: false,
+            mount: ToastMount::InPlace,
+            z_index: None,
+            rate_limit: None,
+        }
+    }
+}
+
+pub(crate) struct ResolvedKindOptions {
+    pub time_out: Duration,
+    pub extended_time_out: Duration,
+    pub close_button: bool,
+    pub progress_bar: bool,
+    pub tap_to_dismiss: bool,
+}
+
+pub(crate) struct KindPresentation {
+    pub class_name: Cow<'static, str>,
+    pub aria_role: Cow<'static, str>,
+    pub aria_live: AriaLive,
+}
+
+impl ToastOptions {
+    pub fn custom_kind(&self, kind: &ToastKind) -> Option<&CustomKind> {
+        match kind {
+            ToastKind::Custom(name) => self.custom_kinds.iter().find(|custom| custom.name == *name),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn presentation(&self, kind: &ToastKind) -> KindPresentation {
+        match self.custom_kind(kind) {
+            Some(custom) => KindPresentation {
+                class_name: custom.class_name.clone(),
+                aria_role: custom.aria_role.clone(),
+                aria_live: custom.aria_live,
+            },
+            None => KindPresentation {
+                class_name: kind.class_name(),
+                aria_role: Cow::Borrowed(kind.aria_role()),
+                aria_live: kind.aria_live(),
+            },
+        }
+    }
+
+    pub(crate) fn resolve_kind(&self, kind: &ToastKind) -> ResolvedKindOptions {
+        let defaults = self.kind_defaults.get(kind);
+        let time_out = defaults
+            .and_then(|defaults| defaults.time_out)
+            .or_else(|| self.custom_kind(kind).and_then(|custom| custom.time_out))
+            .unwrap_or(self.time_out);
+        ResolvedKindOptions {
+            time_out,
+            extended_time_out: defaults
+                .and_then(|defaults| defaults.extended_time_out)
+                .unwrap_or(self.extended_time_out),
+            close_button: defaults
+                .and_then(|defaults| defaults.close_button)
+                .unwrap_or(self.close_button),
+            progress_bar: defaults
+                .and_then(|defaults| defaults.progress_bar)
+                .unwrap_or(self.progress_bar),
+            tap_to_dismiss: defaults
+                .and_then(|defaults| defaults.tap_to_dismiss)
+                .unwrap_or(self.tap_to_dismiss),
+        }
+    }
+
+    pub(crate) fn has_kind_icon(&self, kind: &ToastKind) -> bool {
+        match kind {
+            ToastKind::Custom(_) => self.custom_kind(kind).is_some_and(CustomKind::has_icon),
+            _ => true,
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ToastRequest {
     pub kind: ToastKind,
     pub message: String,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub title: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default, with = "millis::option"))]
     pub time_out: Option<Duration>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub persist: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub confirm: Option<ConfirmButtons>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub icon: ToastIcon,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub system_notification: Option<SystemNotify>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_click: Option<ToastCallback>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub broadcast: bool,
+    /// Groups toasts for `RateLimit::max_per_key`. Defaults to the kind,
+    /// title and message.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rate_key: Option<String>,
+}
+
+/// When a toast is also shown as an operating system notification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SystemNotify {
+    /// Only while the document is hidden, e.g. the user switched tabs.
+    WhenHidden,
+    Always,
+}
+
+/// Shared callback run when a toast, or its system notification, is clicked.
+#[derive(Clone)]
+pub struct ToastCallback(Rc<dyn Fn()>);
+
+impl ToastCallback {
+    pub fn new(callback: impl Fn() + 'static) -> Self {
+        Self(Rc::new(callback))
+    }
+
+    pub fn call(&self) {
+        (self.0)();
+    }
+}
+
+impl PartialEq for ToastCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for ToastCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToastCallback").finish_non_exhaustive()
+    }
+}
+
+/// Icon shown on the left of a toast.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ToastIcon {
+    /// The provider's icon for the kind, or the built-in one.
+    #[default]
+    Default,
+    Hidden,
+    #[cfg(feature = "dioxus")]
+    Custom(Element),
 }
 
 impl ToastRequest {
@@ -78,6 +539,13 @@ impl ToastRequest {
             message: message.into(),
             title: None,
             time_out: None,
+            persist: false,
+            confirm: None,
+            icon: ToastIcon::Default,
+            system_notification: None,
+            on_click: None,
+            broadcast: false,
+            rate_key: None,
         }
     }
 
@@ -90,6 +558,71 @@ impl ToastRequest {
         self.time_out = Some(timeout);
         self
     }
+
+    #[cfg(feature = "dioxus")]
+    pub fn with_icon(mut self, icon: Element) -> Self {
+        self.icon = ToastIcon::Custom(icon);
+        self
+    }
+
+    pub fn without_icon(mut self) -> Self {
+        self.icon = ToastIcon::Hidden;
+        self
+    }
+
+    /// Keep the toast across a navigation or full page reload. The restored
+    /// toast only stays up for the time it had left.
+    pub fn persist(mut self) -> Self {
+        self.persist = true;
+        self
+    }
+
+    /// Also show the toast through the Web Notifications API. Nothing is
+    /// shown until the user has granted permission; ask for it with
+    /// `request_notification_permission()` from a click handler.
+    pub fn system_notification(mut self, mode: SystemNotify) -> Self {
+        self.system_notification = Some(mode);
+        self
+    }
+
+    /// Also show the toast in every other tab whose `ToastProvider` joined the
+    /// same `broadcast_channel`.
+    pub fn broadcast(mut self) -> Self {
+        self.broadcast = true;
+        self
+    }
+
+    /// Runs when the toast is clicked, or when its system notification is
+    /// clicked after the tab has been focused.
+    pub fn on_click(mut self, callback: impl Fn() + 'static) -> Self {
+        self.on_click = Some(ToastCallback::new(callback));
+        self
+    }
+
+    /// Counts this toast against `key` instead of its text for rate limiting,
+    /// e.g. to throttle every error from one websocket together.
+    pub fn rate_key(mut self, key: impl Into<String>) -> Self {
+        self.rate_key = Some(key.into());
+        self
+    }
+
+    /// Confirm and loading toasts are waited on by the caller, so the rate
+    /// limiter never swallows them.
+    pub(crate) fn is_rate_limited(&self) -> bool {
+        self.confirm.is_none() && self.kind != ToastKind::Loading
+    }
+
+    pub(crate) fn resolved_rate_key(&self) -> String {
+        match &self.rate_key {
+            Some(key) => key.clone(),
+            None => format!(
+                "{}\u{0}{}\u{0}{}",
+                self.kind.class_name(),
+                self.title.as_deref().unwrap_or_default(),
+                self.message
+            ),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -99,4 +632,163 @@ pub struct Toast {
     pub message: String,
     pub title: Option<String>,
     pub time_out: Duration,
+    pub created_at: u64,
+    /// When the toast was first shown with a running timer; `None` while it
+    /// waits behind `ToastStore::pause_all`, e.g. in a hidden tab.
+    pub shown_at: Option<u64>,
+    /// Time left on the auto-dismiss timer as of `resumed_at`, or right now
+    /// while the timer is paused.
+    pub remaining: Duration,
+    /// When the timer last started running; `None` while paused or sticky.
+    pub resumed_at: Option<u64>,
+    pub persist: bool,
+    pub confirm: Option<ConfirmButtons>,
+    /// Determinate progress in `0.0..=1.0`, set with `ToastStore::set_progress`.
+    pub progress: Option<f32>,
+    pub icon: ToastIcon,
+    pub on_click: Option<ToastCallback>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConfirmButtons {
+    pub confirm_label: String,
+    pub cancel_label: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfirmOptions {
+    pub kind: ToastKind,
+    pub title: Option<String>,
+    pub confirm_label: String,
+    pub cancel_label: String,
+    /// `None` keeps the toast up until one of the buttons is clicked.
+    pub time_out: Option<Duration>,
+}
+
+impl Default for ConfirmOptions {
+    fn default() -> Self {
+        Self {
+            kind: ToastKind::Warning,
+            title: None,
+            confirm_label: "Confirm".to_string(),
+            cancel_label: "Cancel".to_string(),
+            time_out: None,
+        }
+    }
+}
+
+impl ConfirmOptions {
+    pub(crate) fn into_request(self, message: String) -> ToastRequest {
+        let mut request = ToastRequest::new(self.kind, message)
+            .with_timeout(self.time_out.unwrap_or(Duration::ZERO));
+        request.title = self.title;
+        request.confirm = Some(ConfirmButtons {
+            confirm_label: self.confirm_label,
+            cancel_label: self.cancel_label,
+        });
+        request
+    }
+}
+
+impl Toast {
+    pub fn is_sticky(&self) -> bool {
+        self.time_out.is_zero()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        !self.is_sticky() && self.resumed_at.is_none()
+    }
+
+    pub fn remaining_at(&self, now: u64) -> Duration {
+        match self.resumed_at {
+            Some(resumed_at) => self
+                .remaining
+                .saturating_sub(Duration::from_millis(now.saturating_sub(resumed_at))),
+            None => self.remaining,
+        }
+    }
+
+    /// Milliseconds since the Unix epoch at which the toast would time out if
+    /// it kept running from `now`.
+    pub fn expires_at(&self, now: u64) -> Option<u64> {
+        if self.is_sticky() {
+            return None;
+        }
+        Some(now + self.remaining_at(now).as_millis() as u64)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HistoryEntry {
+    pub id: ToastId,
+    pub kind: ToastKind,
+    pub message: String,
+    pub title: Option<String>,
+    pub created_at: u64,
+    pub dismissed_at: u64,
+    pub reason: DismissReason,
+    pub read: bool,
+}
+
+#[cfg(feature = "serde")]
+pub(crate) mod millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+        use std::time::Duration;
+
+        pub fn serialize<S: Serializer>(
+            value: &Option<Duration>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(value) => serializer.serialize_some(&(value.as_millis() as u64)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            Option::<u64>::deserialize(deserializer).map(|value| value.map(Duration::from_millis))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "persist"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_serialize_as_plain_strings() {
+        let options = ToastOptions {
+            kind_defaults: HashMap::from([
+                (ToastKind::Error, KindOptions::default()),
+                (ToastKind::custom("billing"), KindOptions::default()),
+            ]),
+            ..ToastOptions::default()
+        };
+        let json = serde_json::to_string(&options).unwrap();
+        assert!(json.contains("\"custom:billing\":"), "{json}");
+        assert_eq!(
+            serde_json::from_str::<ToastOptions>(&json).unwrap(),
+            options
+        );
+        assert_eq!(
+            serde_json::to_string(&ToastKind::Warning).unwrap(),
+            "\"warning\""
+        );
+    }
 }
@@ -0,0 +1,69 @@
+use crate::store::ToastStore;
+use dioxus::document::Eval;
+use dioxus::prelude::*;
+
+const LISTENER_JS: &str = r#"
+    const [onHidden, onBlur] = await dioxus.recv();
+    let suspended = false;
+    const update = () => {
+        const next = (onHidden && document.visibilityState === "hidden")
+            || (onBlur && !document.hasFocus());
+        if (next !== suspended) {
+            suspended = next;
+            dioxus.send(suspended);
+        }
+    };
+    document.addEventListener("visibilitychange", update);
+    window.addEventListener("focus", update);
+    window.addEventListener("blur", update);
+    update();
+    await dioxus.recv();
+    document.removeEventListener("visibilitychange", update);
+    window.removeEventListener("focus", update);
+    window.removeEventListener("blur", update);
+"#;
+
+/// Pauses every toast timer while the page is hidden or the window has lost
+/// focus, depending on `pause_on_page_hidden` and `pause_on_window_blur`.
+pub(crate) fn use_visibility_pause(store: ToastStore) {
+    let mut listener = use_signal(|| None::<(bool, bool, Eval)>);
+
+    use_effect(move || {
+        let options = store.options();
+        let wanted = (options.pause_on_page_hidden, options.pause_on_window_blur);
+        if listener
+            .peek()
+            .as_ref()
+            .is_some_and(|(hidden, blur, _)| (*hidden, *blur) == wanted)
+        {
+            return;
+        }
+        stop(listener, store);
+        if wanted == (false, false) {
+            return;
+        }
+        let mut eval = document::eval(LISTENER_JS);
+        if eval.send(wanted).is_err() {
+            return;
+        }
+        listener.set(Some((wanted.0, wanted.1, eval)));
+        spawn(async move {
+            while let Ok(hidden) = eval.recv::<bool>().await {
+                if hidden {
+                    store.pause_all();
+                } else {
+                    store.resume_all();
+                }
+            }
+        });
+    });
+
+    use_drop(move || stop(listener, store));
+}
+
+fn stop(mut listener: Signal<Option<(bool, bool, Eval)>>, store: ToastStore) {
+    if let Some((_, _, eval)) = listener.write().take() {
+        let _ = eval.send(());
+        store.resume_all();
+    }
+}
@@ -0,0 +1,38 @@
+use dioxus::prelude::*;
+use dioxus_toastr::testing::{advance, settle, TestToastProvider};
+use dioxus_toastr::{use_toast, ToastKind, ToastOptions, ToastRequest};
+use std::time::Duration;
+
+#[component]
+fn Save() -> Element {
+    let toast = use_toast();
+    use_hook(|| {
+        toast.push(ToastRequest::new(ToastKind::Error, "Saving failed"));
+        toast.push(
+            ToastRequest::new(ToastKind::Success, "Draft kept")
+                .with_timeout(Duration::from_secs(10)),
+        );
+    });
+    rsx! {}
+}
+
+#[test]
+fn countdown_label_follows_the_clock() {
+    let options = ToastOptions {
+        countdown: true,
+        ..ToastOptions::default()
+    };
+    let mut dom = VirtualDom::new_with_props(
+        |options: ToastOptions| rsx! { TestToastProvider { options, Save {} } },
+        options,
+    );
+    dom.rebuild_in_place();
+    settle(&mut dom);
+    let html = dioxus::ssr::render(&dom);
+    assert!(html.contains("closes in 5s"), "{html}");
+
+    advance(&mut dom, Duration::from_millis(2_100));
+    let html = dioxus::ssr::render(&dom);
+    assert!(html.contains("closes in 3s"), "{html}");
+    assert!(html.contains("closes in 8s"), "{html}");
+}
@@ -0,0 +1,43 @@
+use dioxus_toastr::testing::render_toast_with;
+use dioxus_toastr::{CustomKind, ToastKind, ToastOptions, ToastRequest};
+
+#[test]
+fn custom_kind_colors_stay_in_the_toast_style() {
+    let options = ToastOptions {
+        custom_kinds: vec![CustomKind::new("billing").with_colors("#6F42C1", "</style><b>")],
+        ..ToastOptions::default()
+    };
+    let html = render_toast_with(
+        ToastRequest::new(ToastKind::custom("billing"), "Invoice paid"),
+        options,
+    );
+    assert!(
+        html.contains("style=\"background-color: #6F42C1;color: &#60;/style&#62;&#60;b&#62;;"),
+        "{html}"
+    );
+    assert!(!html.contains("<b>"), "{html}");
+}
+
+#[test]
+fn custom_kind_icon_wins_over_its_preset() {
+    let options = ToastOptions {
+        custom_kinds: vec![CustomKind::from_preset("security", &ToastKind::Error)
+            .with_icon("url(\"/shield.svg\")")],
+        ..ToastOptions::default()
+    };
+    let html = render_toast_with(
+        ToastRequest::new(ToastKind::custom("security"), "New sign-in"),
+        options.clone(),
+    );
+    assert!(html.contains("toast-error"), "{html}");
+    assert!(
+        html.contains("background-image: url(&#34;/shield.svg&#34;) !important;"),
+        "{html}"
+    );
+
+    let hidden = render_toast_with(
+        ToastRequest::new(ToastKind::custom("security"), "New sign-in").without_icon(),
+        options,
+    );
+    assert!(!hidden.contains("shield.svg"), "{hidden}");
+}
@@ -0,0 +1,41 @@
+use dioxus::prelude::*;
+use dioxus_toastr::testing::{toasts, TestToastProvider};
+use dioxus_toastr::{use_toast, ConfirmOptions, RateLimit, ToastKind, ToastOptions};
+use std::time::Duration;
+
+#[component]
+fn Burst() -> Element {
+    let toast = use_toast();
+    use_hook(|| {
+        for _ in 0..3 {
+            toast.info("Reconnecting");
+        }
+        toast.loading("Uploading");
+        drop(toast.confirm("Discard draft?", ConfirmOptions::default()));
+    });
+    rsx! {}
+}
+
+#[test]
+fn rate_limit_counts_repeats_but_lets_loading_and_confirm_through() {
+    let options = ToastOptions {
+        rate_limit: Some(RateLimit::new(1, Duration::from_secs(10))),
+        ..ToastOptions::default()
+    };
+    let mut dom = VirtualDom::new_with_props(
+        |options: ToastOptions| rsx! { TestToastProvider { options, Burst {} } },
+        options,
+    );
+    dom.rebuild_in_place();
+
+    toasts().expect_one(ToastKind::Loading);
+    toasts().expect_count(4);
+    let messages: Vec<_> = toasts()
+        .all()
+        .iter()
+        .map(|toast| toast.message.clone())
+        .collect();
+    assert!(messages.contains(&"Reconnecting".to_string()));
+    assert!(messages.contains(&"Discard draft?".to_string()));
+    assert!(messages.contains(&"2 similar notifications suppressed".to_string()));
+}
@@ -0,0 +1,76 @@
+use dioxus::prelude::*;
+use dioxus_toastr::testing::{settle, TestToastProvider};
+use dioxus_toastr::{use_toast_in, ToastProvider};
+use std::cell::Cell;
+
+thread_local! {
+    static FOUND: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+#[component]
+fn EditorToolbar() -> Element {
+    let editor = use_toast_in("editor");
+    FOUND.set(Some(editor.is_some()));
+    rsx! {}
+}
+
+fn app() -> Element {
+    let show_editor = use_context_provider(|| Signal::new(true));
+    rsx! {
+        TestToastProvider {
+            EditorToolbar {}
+            if show_editor() {
+                ToastProvider { name: "editor" }
+            }
+        }
+    }
+}
+
+fn set_editor(dom: &mut VirtualDom, shown: bool) {
+    dom.in_scope(ScopeId::APP, || {
+        consume_context::<Signal<bool>>().set(shown);
+    });
+    settle(dom);
+}
+
+#[test]
+fn use_toast_in_follows_the_named_provider() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    settle(&mut dom);
+    assert_eq!(FOUND.get(), Some(true));
+
+    set_editor(&mut dom, false);
+    assert_eq!(FOUND.get(), Some(false));
+
+    set_editor(&mut dom, true);
+    assert_eq!(FOUND.get(), Some(true));
+}
+
+#[test]
+fn use_toast_in_is_none_for_an_unknown_name() {
+    fn lookup() -> Element {
+        FOUND.set(Some(use_toast_in("missing").is_some()));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(lookup);
+    dom.rebuild_in_place();
+    assert_eq!(FOUND.get(), Some(false));
+}
+
+#[test]
+fn each_container_gets_its_own_id_and_the_shared_class() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    settle(&mut dom);
+    let html = dioxus::ssr::render(&dom);
+    assert!(
+        html.contains(r#"id="toast-container" class="toast-container toast-top-right"#),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"id="toast-container-editor" class="toast-container toast-top-right"#),
+        "{html}"
+    );
+}
@@ -0,0 +1,82 @@
+use dioxus::prelude::*;
+use dioxus_toastr::testing::{advance, render_toast, toasts, TestToastProvider};
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::time::Duration;
+
+#[component]
+fn Save() -> Element {
+    let toast = use_toast();
+    use_hook(|| {
+        toast.push(ToastRequest::new(ToastKind::Error, "Saving failed").with_title("Sync"));
+        toast.push(
+            ToastRequest::new(ToastKind::Success, "Draft kept")
+                .with_timeout(Duration::from_secs(10)),
+        );
+    });
+    rsx! {}
+}
+
+fn app() -> Element {
+    rsx! {
+        TestToastProvider { Save {} }
+    }
+}
+
+#[test]
+fn expect_one_matches_kind_message_and_title() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    toasts().expect_count(2);
+    toasts()
+        .expect_one(ToastKind::Error)
+        .with_message("Saving failed")
+        .with_message_containing("failed")
+        .with_title("Sync");
+}
+
+#[test]
+fn advance_expires_each_toast_at_its_own_timeout() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    advance(&mut dom, Duration::from_millis(4_999));
+    toasts().expect_count(2);
+    advance(&mut dom, Duration::from_millis(1));
+    toasts()
+        .expect_count(1)
+        .expect_one(ToastKind::Success)
+        .with_message("Draft kept");
+    advance(&mut dom, Duration::from_secs(5));
+    toasts().expect_none();
+}
+
+#[test]
+#[should_panic(expected = "expected exactly one Warning toast")]
+fn expect_one_panics_without_a_match() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    toasts().expect_one(ToastKind::Warning);
+}
+
+#[test]
+#[should_panic(expected = "expected a message containing \"saved\"")]
+fn with_message_containing_panics_on_other_text() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    toasts()
+        .expect_one(ToastKind::Error)
+        .with_message_containing("saved");
+}
+
+#[test]
+fn render_toast_snapshot() {
+    let html = render_toast(ToastRequest::new(ToastKind::Success, "Saved").with_title("Sync"));
+    assert_eq!(
+        html,
+        "<div class=\"toast toast-success\" data-toast-swipe=\"true\" role=\"status\" aria-live=\"polite\" \
+         style=\"animation: toast-in 300ms ease-out; touch-action: pan-y; \">\
+         <div class=\"toast-title\">Sync</div>\
+         <div class=\"toast-message\">Saved</div></div>"
+    );
+}
@@ -0,0 +1,180 @@
+use dioxus::prelude::*;
+use dioxus_toastr::testing::{advance, settle, toasts, TestToastProvider};
+use dioxus_toastr::{
+    use_toast, KindOptions, MockClock, ToastClockHandle, ToastKind, ToastOptions, ToastProvider,
+    ToastStore,
+};
+use std::cell::Cell;
+use std::time::Duration;
+
+#[component]
+fn Pusher(message: String) -> Element {
+    let toast = use_toast();
+    use_hook(|| toast.info(message.clone()));
+    rsx! {}
+}
+
+/// Mounts one pusher that stays and one that unmounts once `show_transient`
+/// turns false.
+fn app() -> Element {
+    let show_transient = use_context_provider(|| Signal::new(true));
+    rsx! {
+        TestToastProvider {
+            Pusher { message: "stays mounted" }
+            if show_transient() {
+                Pusher { message: "unmounts" }
+            }
+        }
+    }
+}
+
+fn unmount_transient(dom: &mut VirtualDom) {
+    dom.in_scope(ScopeId::APP, || {
+        consume_context::<Signal<bool>>().set(false);
+    });
+    settle(dom);
+}
+
+#[test]
+fn toasts_expire_after_the_pushing_component_unmounts() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    toasts().expect_count(2);
+
+    unmount_transient(&mut dom);
+    advance(&mut dom, Duration::from_secs(60));
+    toasts().expect_none();
+}
+
+#[test]
+fn toasts_expire_while_every_pusher_stays_mounted() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    toasts().expect_count(2);
+
+    advance(&mut dom, Duration::from_secs(60));
+    toasts().expect_none();
+}
+
+thread_local! {
+    static STORE: Cell<Option<ToastStore>> = const { Cell::new(None) };
+}
+
+#[component]
+fn CaptureStore() -> Element {
+    let store = use_context::<ToastStore>();
+    use_hook(|| STORE.set(Some(store)));
+    rsx! {}
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ClockedProps {
+    clock: MockClock,
+    options: ToastOptions,
+}
+
+fn clocked(props: ClockedProps) -> Element {
+    let clock = use_hook(|| ToastClockHandle::new(props.clock.clone()));
+    rsx! {
+        ToastProvider { options: props.options.clone(), clock,
+            CaptureStore {}
+            Pusher { message: "saved" }
+        }
+    }
+}
+
+fn mount_clocked(options: ToastOptions) -> (VirtualDom, MockClock, ToastStore) {
+    let clock = MockClock::new(10_000);
+    let mut dom = VirtualDom::new_with_props(
+        clocked,
+        ClockedProps {
+            clock: clock.clone(),
+            options,
+        },
+    );
+    dom.rebuild_in_place();
+    let store = STORE.get().expect("store captured");
+    (dom, clock, store)
+}
+
+fn visible(store: ToastStore) -> usize {
+    store.peek_toasts().len()
+}
+
+#[test]
+fn store_timeout_fires_after_mock_clock_advance() {
+    let (mut dom, clock, store) = mount_clocked(ToastOptions::default());
+    assert_eq!(visible(store), 1);
+
+    clock.advance(Duration::from_millis(4_999));
+    settle(&mut dom);
+    assert_eq!(visible(store), 1);
+
+    clock.advance(Duration::from_millis(1));
+    settle(&mut dom);
+    assert_eq!(visible(store), 0);
+}
+
+#[test]
+fn hover_resumes_with_the_kind_extended_timeout() {
+    let options = ToastOptions {
+        kind_defaults: [(
+            ToastKind::Info,
+            KindOptions {
+                extended_time_out: Some(Duration::from_secs(3)),
+                ..KindOptions::default()
+            },
+        )]
+        .into(),
+        ..ToastOptions::default()
+    };
+    let (mut dom, clock, store) = mount_clocked(options);
+    let id = store.peek_toasts()[0].id;
+
+    dom.in_runtime(|| store.pause(id));
+    clock.advance(Duration::from_secs(60));
+    settle(&mut dom);
+    assert_eq!(visible(store), 1);
+
+    dom.in_runtime(|| store.resume_extended(id));
+    clock.advance(Duration::from_millis(2_999));
+    settle(&mut dom);
+    assert_eq!(visible(store), 1);
+
+    clock.advance(Duration::from_millis(1));
+    settle(&mut dom);
+    assert_eq!(visible(store), 0);
+}
+
+#[test]
+fn setters_update_the_rendered_toast() {
+    let (dom, _clock, store) = mount_clocked(ToastOptions::default());
+    let id = store.peek_toasts()[0].id;
+
+    dom.in_runtime(|| {
+        assert!(store.set_kind(id, ToastKind::Success));
+        assert!(store.set_message(id, "done"));
+        assert!(store.set_progress(id, 1.5));
+        assert!(!store.set_progress(id, f32::NAN));
+    });
+    let toast = store.peek_toasts()[0].clone();
+    assert_eq!(toast.kind, ToastKind::Success);
+    assert_eq!(toast.message, "done");
+    assert_eq!(toast.progress, Some(1.0));
+}
+
+#[test]
+fn rescheduling_cancels_the_previous_wake_up() {
+    let (mut dom, clock, store) = mount_clocked(ToastOptions::default());
+    let id = store.peek_toasts()[0].id;
+
+    for _ in 0..10 {
+        dom.in_runtime(|| {
+            store.pause(id);
+            store.resume(id);
+        });
+        settle(&mut dom);
+    }
+    // The store's wake-up plus the toast's own fade-out wake-up.
+    assert_eq!(clock.pending_sleeps(), 2);
+}